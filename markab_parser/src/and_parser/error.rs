@@ -36,6 +36,11 @@ impl<'a, P> Error for AndParserError<'a, P>
 where
 	P: Parser<'a>,
 {
+	fn position(&self) -> usize
+	{
+		self.from
+	}
+
 	fn from(&self, f: &mut Formatter) -> FmtResult
 	{
 		write!(f, "{}", self.from)
@@ -51,8 +56,18 @@ where
 		write!(f, "failed to parse")
 	}
 
+	fn farthest(&self) -> usize
+	{
+		self.cause.farthest()
+	}
+
 	fn causes(&self, f: &mut Formatter, depth: usize) -> FmtResult
 	{
 		self.cause.print(f, depth)
 	}
+
+	fn for_each_cause<'s>(&'s self, f: &mut dyn FnMut(&'s dyn Error))
+	{
+		f(&self.cause)
+	}
 }
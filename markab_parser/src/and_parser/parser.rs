@@ -3,6 +3,9 @@ use crate::{
 		AndParserError,
 		AndParserRequirement,
 	},
+	session,
+	Failure,
+	Nullable,
 	Parser,
 };
 use std::marker::PhantomData;
@@ -41,15 +44,39 @@ where
 	fn parse(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Self::Error>
 	{
 		let from = *pos;
+		let checkpoint = session::checkpoint();
 		self.requirement
 			.parse(src, pos)
-			.map(|res| {
+			.inspect(|_| {
 				*pos = from;
-				res
+				session::rollback(checkpoint);
 			})
 			.map_err(|err| AndParserError::new(from, self.requirement(None), err))
 	}
 
+	fn attempt(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Failure<'a>>
+	{
+		let from = *pos;
+		let checkpoint = session::checkpoint();
+		self.requirement
+			.attempt(src, pos)
+			.inspect(|_| {
+				*pos = from;
+				session::rollback(checkpoint);
+			})
+			.map_err(|failure| failure.at(from))
+	}
+
+	fn nullable(&self) -> Nullable
+	{
+		Nullable::Yes
+	}
+
+	fn cheap_attempt(&self) -> bool
+	{
+		self.requirement.cheap_attempt()
+	}
+
 	fn requirement(&self, _: Option<&Self::RequirementContext>) -> Self::Requirement
 	{
 		AndParserRequirement::new(self.requirement.requirement(None))
@@ -0,0 +1,61 @@
+use crate::Error;
+use std::fmt::{
+	Display,
+	Formatter,
+	Result as FmtResult,
+};
+
+#[derive(Debug)]
+pub struct AnyCharParserError
+{
+	from: usize,
+}
+
+impl AnyCharParserError
+{
+	pub fn new(from: usize) -> Self
+	{
+		Self { from }
+	}
+}
+
+impl Error for AnyCharParserError
+{
+	fn position(&self) -> usize
+	{
+		self.from
+	}
+
+	fn from(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "{}", self.from)
+	}
+
+	fn requirement(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, ".")
+	}
+
+	fn result(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "reached end of input")
+	}
+
+	fn farthest(&self) -> usize
+	{
+		self.from
+	}
+
+	fn causes(&self, _: &mut Formatter, _: usize) -> FmtResult
+	{
+		Ok(())
+	}
+}
+
+impl Display for AnyCharParserError
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		self.print(f, 0)
+	}
+}
@@ -0,0 +1,9 @@
+mod error;
+mod parser;
+mod utility;
+
+pub use self::{
+	error::AnyCharParserError,
+	parser::AnyCharParser,
+	utility::any_char,
+};
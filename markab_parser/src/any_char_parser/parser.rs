@@ -0,0 +1,70 @@
+use crate::{
+	any_char_parser::AnyCharParserError,
+	session,
+	Failure,
+	Needed,
+	Nullable,
+	Parser,
+};
+
+#[derive(Debug, Default)]
+pub struct AnyCharParser;
+
+impl AnyCharParser
+{
+	pub fn new() -> Self
+	{
+		AnyCharParser
+	}
+}
+
+impl<'a> Parser<'a> for AnyCharParser
+{
+	type Error = AnyCharParserError;
+	type Output = &'a str;
+	type Requirement = &'a str;
+	type RequirementContext = ();
+
+	fn parse(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Self::Error>
+	{
+		let src = session::metered(src, *pos);
+		let from = *pos;
+		self.attempt(src, pos)
+			.map_err(|_| AnyCharParserError::new(from))
+	}
+
+	fn attempt(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Failure<'a>>
+	{
+		let src = session::metered(src, *pos);
+		let from = *pos;
+		match src[from..].chars().next()
+		{
+			Some(next) =>
+			{
+				*pos += next.len_utf8();
+				Ok(&src[from..*pos])
+			}
+			None =>
+			{
+				session::record_failure(from, || ".");
+				session::reached_end(src, from, Needed::Size(1));
+				Err(Failure::new(from))
+			}
+		}
+	}
+
+	fn nullable(&self) -> Nullable
+	{
+		Nullable::No
+	}
+
+	fn cheap_attempt(&self) -> bool
+	{
+		true
+	}
+
+	fn requirement(&self, _: Option<&Self::RequirementContext>) -> Self::Requirement
+	{
+		"."
+	}
+}
@@ -0,0 +1,6 @@
+use crate::any_char_parser::AnyCharParser;
+
+pub fn any_char() -> AnyCharParser
+{
+	AnyCharParser::new()
+}
@@ -0,0 +1,103 @@
+use crate::{
+	backreference_parser::BackreferenceParserRequirement,
+	Error,
+};
+use std::fmt::{
+	Display,
+	Formatter,
+	Result as FmtResult,
+};
+
+#[derive(Debug)]
+pub struct BackreferenceParserError<'a>
+{
+	from: usize,
+	requirement: BackreferenceParserRequirement<'a>,
+	src: &'a str,
+}
+
+impl<'a> BackreferenceParserError<'a>
+{
+	pub fn new(from: usize, requirement: BackreferenceParserRequirement<'a>, src: &'a str) -> Self
+	{
+		Self {
+			from,
+			requirement,
+			src,
+		}
+	}
+}
+
+impl<'a> Error for BackreferenceParserError<'a>
+{
+	fn position(&self) -> usize
+	{
+		self.from
+	}
+
+	fn from(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "{}", self.from)
+	}
+
+	fn requirement(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "{}", self.requirement)
+	}
+
+	fn result(&self, f: &mut Formatter) -> FmtResult
+	{
+		match (self.requirement.captured(), self.requirement.is_recorded())
+		{
+			(Some(_), _) => (),
+			(None, true) => return write!(f, "no such capture was made in this rule"),
+			(None, false) =>
+			{
+				return write!(
+					f,
+					"no such capture was made, since captures are only recorded inside a Session \
+					 or a Parseable rule"
+				)
+			}
+		}
+		let found = &self.src[self.from..];
+		let len = found
+			.chars()
+			.take(self.requirement.expected().count())
+			.map(char::len_utf8)
+			.sum::<usize>();
+		if len == 0
+		{
+			write!(f, "not found")
+		}
+		else
+		{
+			write!(f, "{} found", &found[..len])
+		}
+	}
+
+	fn farthest(&self) -> usize
+	{
+		self.from
+			+ self
+				.requirement
+				.expected()
+				.zip(self.src[self.from..].chars())
+				.take_while(|(required, found)| required == found)
+				.map(|(required, _)| required.len_utf8())
+				.sum::<usize>()
+	}
+
+	fn causes(&self, _: &mut Formatter, _: usize) -> FmtResult
+	{
+		Ok(())
+	}
+}
+
+impl<'a> Display for BackreferenceParserError<'a>
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		self.print(f, 0)
+	}
+}
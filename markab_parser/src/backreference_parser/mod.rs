@@ -0,0 +1,11 @@
+mod error;
+mod parser;
+mod requirement;
+mod utility;
+
+pub use self::{
+	error::BackreferenceParserError,
+	parser::BackreferenceParser,
+	requirement::BackreferenceParserRequirement,
+	utility::backreference,
+};
@@ -0,0 +1,278 @@
+use crate::{
+	backreference_parser::{
+		BackreferenceParserError,
+		BackreferenceParserRequirement,
+	},
+	diagnostic::Location,
+	session,
+	Failure,
+	Parser,
+};
+
+#[derive(Debug)]
+pub struct BackreferenceParser<'a>
+{
+	name: &'a str,
+	label: Option<&'a str>,
+	open: &'a str,
+	close: &'a str,
+}
+
+impl<'a> BackreferenceParser<'a>
+{
+	pub fn new(name: &'a str) -> Self
+	{
+		Self {
+			name,
+			label: None,
+			open: "",
+			close: "",
+		}
+	}
+
+	pub fn label(self, label: &'a str) -> Self
+	{
+		Self {
+			label: Some(label),
+			..self
+		}
+	}
+
+	pub fn enclosed(self, open: &'a str, close: &'a str) -> Self
+	{
+		Self {
+			open,
+			close,
+			..self
+		}
+	}
+
+	fn captured(&self, src: &'a str) -> Option<(&'a str, usize)>
+	{
+		session::captured(self.name).and_then(|range| {
+			src.get(range.clone())
+				.map(|captured| (captured, range.start))
+		})
+	}
+
+	fn matches(&self, src: &'a str, pos: &mut usize) -> bool
+	{
+		let captured = match self.captured(src)
+		{
+			Some((captured, _)) => captured,
+			None => return false,
+		};
+		let mut end = *pos;
+		for part in [self.open, captured, self.close]
+		{
+			if !src[end..].starts_with(part)
+			{
+				return false;
+			}
+			end += part.len();
+		}
+		*pos = end;
+		true
+	}
+
+	fn expected(&self, src: &'a str) -> impl Iterator<Item = char> + 'a
+	{
+		let captured = self.captured(src).map_or("", |(captured, _)| captured);
+		self.open
+			.chars()
+			.chain(captured.chars())
+			.chain(self.close.chars())
+	}
+}
+
+impl<'a> Parser<'a> for BackreferenceParser<'a>
+{
+	type Error = BackreferenceParserError<'a>;
+	type Output = &'a str;
+	type Requirement = BackreferenceParserRequirement<'a>;
+	type RequirementContext = &'a str;
+
+	fn parse(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Self::Error>
+	{
+		let src = session::metered(src, *pos);
+		session::lexeme(src, pos, |pos| {
+			let from = *pos;
+			if self.matches(src, pos)
+			{
+				Ok(&src[from..*pos])
+			}
+			else
+			{
+				session::record_failure(from, || self.requirement(Some(&src)));
+				session::reached_end_of_literal(src, from, self.expected(src), |a, b| a == b);
+				Err(BackreferenceParserError::new(
+					from,
+					self.requirement(Some(&src)),
+					src,
+				))
+			}
+		})
+	}
+
+	fn attempt(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Failure<'a>>
+	{
+		let src = session::metered(src, *pos);
+		session::lexeme(src, pos, |pos| {
+			let from = *pos;
+			if self.matches(src, pos)
+			{
+				Ok(&src[from..*pos])
+			}
+			else
+			{
+				session::record_failure(from, || self.requirement(Some(&src)));
+				session::reached_end_of_literal(src, from, self.expected(src), |a, b| a == b);
+				Err(Failure::new(from))
+			}
+		})
+	}
+
+	fn cheap_attempt(&self) -> bool
+	{
+		true
+	}
+
+	fn requirement(&self, src: Option<&Self::RequirementContext>) -> Self::Requirement
+	{
+		let captured = src.and_then(|src| {
+			self.captured(src)
+				.map(|(captured, start)| (captured, Location::new(src, start)))
+		});
+		BackreferenceParserRequirement::new(
+			self.name,
+			self.label,
+			self.open,
+			self.close,
+			captured,
+			session::capturing(),
+		)
+	}
+}
+
+#[test]
+fn test()
+{
+	use crate::{
+		backreference,
+		capture_parser::CaptureParser,
+		sequence_parser::{
+			SequenceParser,
+			SequenceParserError,
+		},
+		string,
+		string_parser::StringParser,
+		take_while,
+		take_while_parser::TakeWhileParser,
+		Parseable,
+		Session,
+	};
+
+	type Name<'a> = CaptureParser<'a, TakeWhileParser<'a>>;
+
+	struct Inner;
+
+	impl<'a> Parseable<'a> for Inner
+	{
+		type Error = SequenceParserError<
+			'a,
+			SequenceParser<'a, Name<'a>, StringParser<'a>>,
+			BackreferenceParser<'a>,
+		>;
+		type Output = ();
+
+		fn parse(src: &'a str, pos: &mut usize) -> Result<Self::Output, Self::Error>
+		{
+			take_while("name", &char::is_alphabetic, 1, usize::MAX)
+				.capture("tag")
+				.and_then(string("-"))
+				.and_then(backreference("tag"))
+				.skip(src, pos)
+		}
+
+		fn name() -> &'a str
+		{
+			"inner"
+		}
+	}
+
+	let parser = string("<")
+		.and_then(take_while("name", &char::is_alphabetic, 1, usize::MAX).capture("tag"))
+		.and_then(string(">\n"))
+		.and_then(Inner::get_parser())
+		.and_then(
+			backreference("tag")
+				.label("closing tag")
+				.enclosed("</", ">"),
+		);
+	let session = Session::new();
+	let mut pos = 0;
+	assert!(session
+		.parse(&parser, "<item>\nx-x</item>", &mut pos)
+		.is_ok());
+	assert_eq!(pos, 17);
+	let mut pos = 0;
+	let err = session
+		.parse(&parser, "<item>\nx-x</itme>", &mut pos)
+		.err()
+		.unwrap();
+	assert!(format!("{}", err)
+		.contains("required closing tag `</item>` to match opening at 1:2 but </itme> found."));
+	let mut pos = 0;
+	assert!(session
+		.parse(&parser, "<item>\nx-y</item>", &mut pos)
+		.is_err());
+	let capture = take_while("name", &char::is_alphabetic, 1, usize::MAX).capture("tag");
+	let mut pos = 0;
+	assert!(capture.parse("héllo", &mut pos).is_ok());
+	let mut pos = 0;
+	assert!(backreference("tag").parse("xy", &mut pos).is_err());
+	assert!(session.run(|| {
+		let mut pos = 0;
+		capture.skip("héllo", &mut pos).is_ok() && backreference("tag").skip("xy", &mut 0).is_err()
+	}));
+	let mut pos = 0;
+	let err = capture
+		.and_then(backreference("tag"))
+		.parse("tag tag", &mut pos)
+		.err()
+		.unwrap();
+	assert!(format!("{}", err).ends_with(
+		"\tat position 3 required matching capture `tag` but no such capture was made, since \
+		 captures are only recorded inside a Session or a Parseable rule.\n"
+	));
+
+	struct Opening;
+
+	impl<'a> Parseable<'a> for Opening
+	{
+		type Error = crate::take_while_parser::TakeWhileParserError<'a>;
+		type Output = &'a str;
+
+		fn parse(src: &'a str, pos: &mut usize) -> Result<Self::Output, Self::Error>
+		{
+			take_while("name", &char::is_alphabetic, 1, usize::MAX)
+				.capture("opening")
+				.parse(src, pos)
+		}
+
+		fn name() -> &'a str
+		{
+			"opening"
+		}
+	}
+
+	let parser = Opening::get_parser()
+		.and_then(string("-"))
+		.and_then(backreference("opening"));
+	let mut pos = 0;
+	let err = session.parse(&parser, "ab-ab", &mut pos).err().unwrap();
+	assert!(format!("{}", err).ends_with(
+		"\tat position 3 required matching capture `opening` but no such capture was made in this \
+		 rule.\n"
+	));
+}
@@ -0,0 +1,103 @@
+use crate::diagnostic::Location;
+use std::fmt::{
+	Display,
+	Formatter,
+	Result as FmtResult,
+};
+
+#[derive(Debug, Clone, Copy)]
+enum Captured<'a>
+{
+	Found(&'a str, Location),
+	Missing
+	{
+		recorded: bool,
+	},
+}
+
+#[derive(Debug)]
+pub struct BackreferenceParserRequirement<'a>
+{
+	name: &'a str,
+	label: Option<&'a str>,
+	open: &'a str,
+	close: &'a str,
+	captured: Captured<'a>,
+}
+
+impl<'a> BackreferenceParserRequirement<'a>
+{
+	pub fn new(
+		name: &'a str,
+		label: Option<&'a str>,
+		open: &'a str,
+		close: &'a str,
+		captured: Option<(&'a str, Location)>,
+		recorded: bool,
+	) -> Self
+	{
+		let captured = match captured
+		{
+			Some((captured, location)) => Captured::Found(captured, location),
+			None => Captured::Missing { recorded },
+		};
+		Self {
+			name,
+			label,
+			open,
+			close,
+			captured,
+		}
+	}
+
+	pub fn name(&self) -> &'a str
+	{
+		self.name
+	}
+
+	pub fn captured(&self) -> Option<(&'a str, Location)>
+	{
+		match self.captured
+		{
+			Captured::Found(captured, location) => Some((captured, location)),
+			Captured::Missing { .. } => None,
+		}
+	}
+
+	pub fn is_recorded(&self) -> bool
+	{
+		!matches!(self.captured, Captured::Missing { recorded: false })
+	}
+
+	pub(crate) fn expected(&self) -> impl Iterator<Item = char> + 'a
+	{
+		let captured = self.captured().map_or("", |(captured, _)| captured);
+		self.open
+			.chars()
+			.chain(captured.chars())
+			.chain(self.close.chars())
+	}
+}
+
+impl<'a> Display for BackreferenceParserRequirement<'a>
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		if let Some(label) = self.label
+		{
+			write!(f, "{} ", label)?;
+		}
+		match self.captured
+		{
+			Captured::Found(captured, location) =>
+			{
+				write!(
+					f,
+					"`{}{}{}` to match opening at {}",
+					self.open, captured, self.close, location
+				)
+			}
+			Captured::Missing { .. } => write!(f, "matching capture `{}`", self.name),
+		}
+	}
+}
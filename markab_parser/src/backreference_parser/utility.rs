@@ -0,0 +1,6 @@
+use crate::backreference_parser::BackreferenceParser;
+
+pub fn backreference(name: &str) -> BackreferenceParser<'_>
+{
+	BackreferenceParser::new(name)
+}
@@ -0,0 +1,101 @@
+use crate::Error;
+use std::fmt::{
+	Display,
+	Formatter,
+	Result as FmtResult,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BudgetExhausted
+{
+	offset: usize,
+	budget: usize,
+}
+
+impl BudgetExhausted
+{
+	pub fn new(offset: usize, budget: usize) -> Self
+	{
+		Self { offset, budget }
+	}
+
+	pub fn offset(&self) -> usize
+	{
+		self.offset
+	}
+
+	pub fn budget(&self) -> usize
+	{
+		self.budget
+	}
+}
+
+impl Error for BudgetExhausted
+{
+	fn position(&self) -> usize
+	{
+		self.offset
+	}
+
+	fn from(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "{}", self.offset)
+	}
+
+	fn requirement(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "at most {} parse steps", self.budget)
+	}
+
+	fn result(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "budget exhausted at offset {}", self.offset)
+	}
+
+	fn farthest(&self) -> usize
+	{
+		self.offset
+	}
+
+	fn causes(&self, _: &mut Formatter, _: usize) -> FmtResult
+	{
+		Ok(())
+	}
+}
+
+impl Display for BudgetExhausted
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		self.print(f, 0)
+	}
+}
+
+#[test]
+fn test()
+{
+	use crate::{
+		string,
+		Interrupted,
+		Parser,
+		Session,
+	};
+
+	let parser = string("a").or(string("b")).zero_or_more();
+	let src = "ab".repeat(1000);
+	let mut pos = 0;
+	let result = Session::new().parse_with_budget(&parser, &src, &mut pos, 100);
+	assert_eq!(
+		result.err().and_then(|err| err.right()),
+		Some(Interrupted::BudgetExhausted(BudgetExhausted::new(67, 100)))
+	);
+	assert_eq!(pos, 0);
+	let mut pos = 0;
+	let result = Session::new().parse_with_budget(&parser, "abba", &mut pos, 100);
+	assert_eq!(result.ok().map(|res| res.len()), Some(4));
+	assert_eq!(pos, 4);
+	assert_eq!(
+		format!("{}", BudgetExhausted::new(67, 100)),
+		"at position 67 required at most 100 parse steps but budget exhausted at offset 67.\n"
+	);
+}
@@ -0,0 +1,92 @@
+use crate::{
+	session::{
+		self,
+		TriviaGuard,
+	},
+	Failure,
+	Nullable,
+	Parser,
+};
+use std::marker::PhantomData;
+
+#[derive(Debug)]
+pub struct CaptureParser<'a, P>
+where
+	P: Parser<'a>,
+{
+	requirement: P,
+	name: &'static str,
+	_a: PhantomData<&'a ()>,
+}
+
+impl<'a, P> CaptureParser<'a, P>
+where
+	P: Parser<'a>,
+{
+	pub fn new(requirement: P, name: &'static str) -> Self
+	{
+		Self {
+			requirement,
+			name,
+			_a: PhantomData,
+		}
+	}
+}
+
+impl<'a, P> Parser<'a> for CaptureParser<'a, P>
+where
+	P: Parser<'a>,
+{
+	type Error = P::Error;
+	type Output = P::Output;
+	type Requirement = P::Requirement;
+	type RequirementContext = P::RequirementContext;
+
+	fn parse(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Self::Error>
+	{
+		session::lexeme(src, pos, |pos| {
+			let _guard = TriviaGuard::disable();
+			let from = *pos;
+			let res = self.requirement.parse(src, pos)?;
+			session::capture(self.name, from..*pos);
+			Ok(res)
+		})
+	}
+
+	fn skip(&self, src: &'a str, pos: &mut usize) -> Result<(), Self::Error>
+	{
+		session::lexeme(src, pos, |pos| {
+			let _guard = TriviaGuard::disable();
+			let from = *pos;
+			self.requirement.skip(src, pos)?;
+			session::capture(self.name, from..*pos);
+			Ok(())
+		})
+	}
+
+	fn attempt(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Failure<'a>>
+	{
+		session::lexeme(src, pos, |pos| {
+			let _guard = TriviaGuard::disable();
+			let from = *pos;
+			let res = self.requirement.attempt(src, pos)?;
+			session::capture(self.name, from..*pos);
+			Ok(res)
+		})
+	}
+
+	fn nullable(&self) -> Nullable
+	{
+		self.requirement.nullable()
+	}
+
+	fn cheap_attempt(&self) -> bool
+	{
+		self.requirement.cheap_attempt()
+	}
+
+	fn requirement(&self, context: Option<&Self::RequirementContext>) -> Self::Requirement
+	{
+		self.requirement.requirement(context)
+	}
+}
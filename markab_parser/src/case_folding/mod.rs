@@ -0,0 +1,98 @@
+mod table;
+
+use either::{
+	Either,
+	Left,
+	Right,
+};
+use std::{
+	fmt::{
+		Display,
+		Formatter,
+		Result as FmtResult,
+	},
+	iter::{
+		once,
+		Once,
+	},
+	str::Chars,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaseFolding
+{
+	Ascii,
+	Unicode,
+}
+
+impl Display for CaseFolding
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		match self
+		{
+			CaseFolding::Ascii => write!(f, "ASCII"),
+			CaseFolding::Unicode => write!(f, "Unicode"),
+		}
+	}
+}
+
+impl CaseFolding
+{
+	pub fn eq_char(self, a: char, b: char) -> bool
+	{
+		match self
+		{
+			CaseFolding::Ascii => a.eq_ignore_ascii_case(&b),
+			CaseFolding::Unicode => a == b || fold(a).eq(fold(b)),
+		}
+	}
+
+	pub fn prefix_len(self, requirement: &str, src: &str) -> Option<usize>
+	{
+		match self
+		{
+			CaseFolding::Ascii =>
+			{
+				let len = requirement.len();
+				src.get(..len)
+					.filter(|found| found.eq_ignore_ascii_case(requirement))
+					.map(|_| len)
+			}
+			CaseFolding::Unicode =>
+			{
+				let mut required = requirement.chars().flat_map(fold);
+				let mut pending = required.next();
+				let mut len = 0;
+				let mut found = src.chars();
+				while pending.is_some()
+				{
+					let next = found.next()?;
+					for folded in fold(next)
+					{
+						if Some(folded) != pending
+						{
+							return None;
+						}
+						pending = required.next();
+					}
+					len += next.len_utf8();
+				}
+				Some(len)
+			}
+		}
+	}
+}
+
+fn fold(c: char) -> Either<Once<char>, Chars<'static>>
+{
+	if c.is_ascii()
+	{
+		return Left(once(c.to_ascii_lowercase()));
+	}
+	match table::CASE_FOLDING.binary_search_by_key(&c, |&(c, _)| c)
+	{
+		Ok(index) => Right(table::CASE_FOLDING[index].1.chars()),
+		Err(_) => Left(once(c)),
+	}
+}
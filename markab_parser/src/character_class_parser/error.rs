@@ -34,6 +34,11 @@ impl<'a> CharacterClassParserError<'a>
 
 impl<'a> Error for CharacterClassParserError<'a>
 {
+	fn position(&self) -> usize
+	{
+		self.from
+	}
+
 	fn from(&self, f: &mut Formatter) -> FmtResult
 	{
 		write!(f, "{}", self.from)
@@ -53,6 +58,11 @@ impl<'a> Error for CharacterClassParserError<'a>
 		}
 	}
 
+	fn farthest(&self) -> usize
+	{
+		self.from
+	}
+
 	fn causes(&self, _: &mut Formatter, _: usize) -> FmtResult
 	{
 		Ok(())
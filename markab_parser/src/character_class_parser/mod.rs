@@ -7,5 +7,8 @@ pub use self::{
 	error::CharacterClassParserError,
 	parser::CharacterClassParser,
 	requirement::CharacterClassParserRequirement,
-	utility::character_class,
+	utility::{
+		character_class,
+		character_set,
+	},
 };
@@ -3,23 +3,52 @@ use crate::{
 		CharacterClassParserError,
 		CharacterClassParserRequirement,
 	},
+	character_set::CharSet,
+	session,
+	Failure,
+	Needed,
+	Nullable,
 	Parser,
 };
-use std::ops::Range;
+use std::{
+	marker::PhantomData,
+	ops::Range,
+	sync::Arc,
+};
 
 #[derive(Debug)]
 pub struct CharacterClassParser<'a>
 {
-	not: bool,
-	chars: &'a [char],
-	ranges: &'a [Range<char>],
+	set: Arc<CharSet>,
+	_a: PhantomData<&'a ()>,
 }
 
 impl<'a> CharacterClassParser<'a>
 {
-	pub fn new(not: bool, chars: &'a [char], ranges: &'a [Range<char>]) -> Self
+	pub fn new(not: bool, chars: &[char], ranges: &[Range<char>]) -> Self
+	{
+		let set = CharSet::new(chars, ranges);
+		Self::with_set(if not { set.complement() } else { set })
+	}
+
+	pub fn with_set<S>(set: S) -> Self
+	where
+		S: Into<Arc<CharSet>>,
+	{
+		Self {
+			set: set.into(),
+			_a: PhantomData,
+		}
+	}
+
+	pub fn set(&self) -> &CharSet
 	{
-		Self { not, chars, ranges }
+		&self.set
+	}
+
+	fn matches(&self, next: char) -> bool
+	{
+		self.set.contains(next)
 	}
 }
 
@@ -32,73 +61,141 @@ impl<'a> Parser<'a> for CharacterClassParser<'a>
 
 	fn parse(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Self::Error>
 	{
+		let src = session::metered(src, *pos);
 		let from = *pos;
-		if let Some(next) = src[from..].chars().next()
+		match src[from..].chars().next()
 		{
-			if self.not
+			Some(next) if self.matches(next) =>
+			{
+				*pos += next.len_utf8();
+				Ok(&src[from..*pos])
+			}
+			next =>
+			{
+				session::record_failure(from, || self.requirement(None));
+				session::reached_end(src, from, Needed::Size(1));
+				Err(CharacterClassParserError::new(
+					from,
+					self.requirement(None),
+					next,
+				))
+			}
+		}
+	}
+
+	fn attempt(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Failure<'a>>
+	{
+		let src = session::metered(src, *pos);
+		let from = *pos;
+		match src[from..].chars().next()
+		{
+			Some(next) if self.matches(next) =>
 			{
-				for c in self.chars
-				{
-					if next == *c
-					{
-						return Err(CharacterClassParserError::new(
-							from,
-							self.requirement(None),
-							Some(next),
-						));
-					}
-				}
-				for range in self.ranges
-				{
-					if range.start <= next && next <= range.end
-					{
-						return Err(CharacterClassParserError::new(
-							from,
-							self.requirement(None),
-							Some(next),
-						));
-					}
-				}
 				*pos += next.len_utf8();
 				Ok(&src[from..*pos])
 			}
-			else
+			_ =>
+			{
+				session::record_failure(from, || self.requirement(None));
+				session::reached_end(src, from, Needed::Size(1));
+				Err(Failure::new(from))
+			}
+		}
+	}
+
+	fn skip_repeated(&self, src: &'a str, pos: &mut usize, max: usize) -> Option<usize>
+	{
+		let mut src = session::metered(src, *pos);
+		let mut count = 0;
+		while count < max
+		{
+			if count > 0
+			{
+				src = session::metered(src, *pos);
+			}
+			match src.as_bytes().get(*pos)
 			{
-				for c in self.chars
+				Some(&byte) if byte.is_ascii() =>
 				{
-					if next == *c
+					if !self.set.contains_ascii(byte)
 					{
-						*pos += next.len_utf8();
-						return Ok(&src[from..*pos]);
+						break;
 					}
+					*pos += 1;
 				}
-				for range in self.ranges
+				Some(_) =>
 				{
-					if range.start <= next && next <= range.end
+					let next = src[*pos..].chars().next().unwrap();
+					if !self.set.contains(next)
 					{
-						*pos += next.len_utf8();
-						return Ok(&src[from..*pos]);
+						break;
 					}
+					*pos += next.len_utf8();
 				}
-				Err(CharacterClassParserError::new(
-					from,
-					self.requirement(None),
-					Some(next),
-				))
+				None => break,
 			}
+			count += 1;
 		}
-		else
+		if count < max
 		{
-			Err(CharacterClassParserError::new(
-				from,
-				self.requirement(None),
-				None,
-			))
+			session::record_failure(*pos, || self.requirement(None));
+			session::reached_end(src, *pos, Needed::Size(1));
 		}
+		Some(count)
+	}
+
+	fn nullable(&self) -> Nullable
+	{
+		Nullable::No
+	}
+
+	fn cheap_attempt(&self) -> bool
+	{
+		true
 	}
 
 	fn requirement(&self, _: Option<&Self::RequirementContext>) -> Self::Requirement
 	{
-		CharacterClassParserRequirement::new(self.not, &self.chars, &self.ranges)
+		CharacterClassParserRequirement::new(self.set.clone())
 	}
 }
+
+#[test]
+fn test()
+{
+	use crate::{
+		character_class,
+		BudgetExhausted,
+		Incomplete,
+		Interrupted,
+		Session,
+	};
+
+	let parser = character_class(false, &['a', 'b', 'c'], &[])
+		.zero_or_more()
+		.stringify();
+	fn is_send_sync<T: Send + Sync>(_: &T) {}
+	is_send_sync(&parser);
+	let session = Session::new();
+	let mut pos = 0;
+	assert_eq!(
+		session
+			.parse_partial(&parser, "abc", &mut pos)
+			.err()
+			.and_then(|err| err.right()),
+		Some(Interrupted::Incomplete(Incomplete::new(3, Needed::Size(1))))
+	);
+	assert_eq!(
+		session.parse_partial(&parser, "abc;", &mut pos).ok(),
+		Some("abc")
+	);
+	let src = "a".repeat(1000);
+	let mut pos = 0;
+	assert_eq!(
+		session
+			.parse_with_budget(&parser, &src, &mut pos, 100)
+			.err()
+			.and_then(|err| err.right()),
+		Some(Interrupted::BudgetExhausted(BudgetExhausted::new(100, 100)))
+	);
+}
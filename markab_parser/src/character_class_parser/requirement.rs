@@ -1,25 +1,34 @@
+use crate::character_set::CharSet;
 use std::{
 	fmt::{
 		Display,
 		Formatter,
 		Result as FmtResult,
 	},
-	ops::Range,
+	marker::PhantomData,
+	sync::Arc,
 };
 
 #[derive(Debug)]
 pub struct CharacterClassParserRequirement<'a>
 {
-	not: bool,
-	chars: &'a [char],
-	ranges: &'a [Range<char>],
+	set: Arc<CharSet>,
+	_a: PhantomData<&'a ()>,
 }
 
 impl<'a> CharacterClassParserRequirement<'a>
 {
-	pub fn new(not: bool, chars: &'a [char], ranges: &'a [Range<char>]) -> Self
+	pub fn new(set: Arc<CharSet>) -> Self
 	{
-		Self { not, chars, ranges }
+		Self {
+			set,
+			_a: PhantomData,
+		}
+	}
+
+	pub fn set(&self) -> &CharSet
+	{
+		&self.set
 	}
 }
 
@@ -27,19 +36,6 @@ impl<'a> Display for CharacterClassParserRequirement<'a>
 {
 	fn fmt(&self, f: &mut Formatter) -> FmtResult
 	{
-		write!(f, "[")?;
-		if self.not
-		{
-			write!(f, "^")?;
-		}
-		for c in self.chars
-		{
-			write!(f, "{}", c)?;
-		}
-		for range in self.ranges
-		{
-			write!(f, "{}-{}", range.start, range.end)?;
-		}
-		Ok(())
+		write!(f, "{}", self.set)
 	}
 }
@@ -1,11 +1,19 @@
-use crate::character_class_parser::CharacterClassParser;
+use crate::{
+	character_class_parser::CharacterClassParser,
+	character_set::CharSet,
+};
 use std::ops::Range;
 
 pub fn character_class<'a>(
 	not: bool,
-	chars: &'a [char],
-	ranges: &'a [Range<char>],
+	chars: &[char],
+	ranges: &[Range<char>],
 ) -> CharacterClassParser<'a>
 {
 	CharacterClassParser::new(not, chars, ranges)
 }
+
+pub fn character_set<'a>(set: CharSet) -> CharacterClassParser<'a>
+{
+	CharacterClassParser::with_set(set)
+}
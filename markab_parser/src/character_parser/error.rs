@@ -27,6 +27,11 @@ impl CharacterParserError
 
 impl Error for CharacterParserError
 {
+	fn position(&self) -> usize
+	{
+		self.from
+	}
+
 	fn from(&self, f: &mut Formatter) -> FmtResult
 	{
 		write!(f, "{}", self.from)
@@ -46,6 +51,11 @@ impl Error for CharacterParserError
 		}
 	}
 
+	fn farthest(&self) -> usize
+	{
+		self.from
+	}
+
 	fn causes(&self, _: &mut Formatter, _: usize) -> FmtResult
 	{
 		Ok(())
@@ -1,5 +1,10 @@
 use crate::{
 	character_parser::CharacterParserError,
+	scan,
+	session,
+	Failure,
+	Needed,
+	Nullable,
 	Parser,
 };
 
@@ -26,31 +31,63 @@ impl<'a> Parser<'a> for CharacterParser
 
 	fn parse(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Self::Error>
 	{
+		let src = session::metered(src, *pos);
 		let from = *pos;
-		let next = src[from..].chars().next();
-		if next.is_some()
+		match src[from..].chars().next()
 		{
-			let next = next.unwrap();
-			if next == self.requirement
+			Some(next) if next == self.requirement =>
 			{
 				*pos += self.requirement.len_utf8();
 				Ok(&src[from..*pos])
 			}
-			else
+			next =>
 			{
-				Err(CharacterParserError::new(
-					from,
-					self.requirement,
-					Some(next),
-				))
+				session::record_failure(from, || self.requirement(None));
+				session::reached_end(src, from, Needed::Size(1));
+				Err(CharacterParserError::new(from, self.requirement, next))
 			}
 		}
+	}
+
+	fn attempt(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Failure<'a>>
+	{
+		let src = session::metered(src, *pos);
+		let from = *pos;
+		if src[from..].starts_with(self.requirement)
+		{
+			*pos += self.requirement.len_utf8();
+			Ok(&src[from..*pos])
+		}
+		else
+		{
+			session::record_failure(from, || self.requirement(None));
+			session::reached_end(src, from, Needed::Size(1));
+			Err(Failure::new(from))
+		}
+	}
+
+	fn next_candidate(&self, src: &'a str, from: usize) -> Option<usize>
+	{
+		if self.requirement.is_ascii()
+		{
+			scan::find_byte(src, from, self.requirement as u8)
+		}
 		else
 		{
-			Err(CharacterParserError::new(from, self.requirement, None))
+			scan::find_literal(src, from, self.requirement.encode_utf8(&mut [0; 4]))
 		}
 	}
 
+	fn nullable(&self) -> Nullable
+	{
+		Nullable::No
+	}
+
+	fn cheap_attempt(&self) -> bool
+	{
+		true
+	}
+
 	fn requirement(&self, _: Option<&Self::RequirementContext>) -> Self::Requirement
 	{
 		self.requirement
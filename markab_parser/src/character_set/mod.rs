@@ -0,0 +1,9 @@
+mod property;
+mod ranges;
+mod set;
+mod tables;
+
+pub use self::{
+	property::Property,
+	set::CharSet,
+};
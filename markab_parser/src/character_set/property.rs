@@ -0,0 +1,54 @@
+use crate::character_set::{
+	ranges,
+	tables,
+};
+use std::fmt::{
+	Display,
+	Formatter,
+	Result as FmtResult,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Property
+{
+	Letter,
+	Digit,
+	WhiteSpace,
+	XidStart,
+	XidContinue,
+}
+
+impl Property
+{
+	pub fn matches(self, c: char) -> bool
+	{
+		ranges::contains(self.ranges(), c)
+	}
+
+	pub(crate) fn ranges(self) -> &'static [(u32, u32)]
+	{
+		match self
+		{
+			Property::Letter => tables::LETTER,
+			Property::Digit => tables::DIGIT,
+			Property::WhiteSpace => tables::WHITE_SPACE,
+			Property::XidStart => tables::XID_START,
+			Property::XidContinue => tables::XID_CONTINUE,
+		}
+	}
+}
+
+impl Display for Property
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		match self
+		{
+			Property::Letter => write!(f, "Letter"),
+			Property::Digit => write!(f, "Decimal_Number"),
+			Property::WhiteSpace => write!(f, "White_Space"),
+			Property::XidStart => write!(f, "XID_Start"),
+			Property::XidContinue => write!(f, "XID_Continue"),
+		}
+	}
+}
@@ -0,0 +1,91 @@
+const MAX: u32 = char::MAX as u32;
+
+pub fn normalize(mut ranges: Vec<(u32, u32)>) -> Vec<(u32, u32)>
+{
+	ranges.retain(|(start, end)| start <= end);
+	ranges.sort_unstable();
+	let mut merged: Vec<(u32, u32)> = Vec::with_capacity(ranges.len());
+	for (start, end) in ranges
+	{
+		match merged.last_mut()
+		{
+			Some(last) if start <= last.1.saturating_add(1) => last.1 = last.1.max(end),
+			_ => merged.push((start, end)),
+		}
+	}
+	merged
+}
+
+pub fn complement(ranges: &[(u32, u32)]) -> Vec<(u32, u32)>
+{
+	let mut result = Vec::with_capacity(ranges.len() + 1);
+	let mut next = 0;
+	for &(start, end) in ranges
+	{
+		if next < start
+		{
+			result.push((next, start - 1));
+		}
+		next = end + 1;
+	}
+	if next <= MAX
+	{
+		result.push((next, MAX));
+	}
+	result
+}
+
+pub fn union(a: &[(u32, u32)], b: &[(u32, u32)]) -> Vec<(u32, u32)>
+{
+	normalize(a.iter().chain(b).copied().collect())
+}
+
+pub fn intersection(a: &[(u32, u32)], b: &[(u32, u32)]) -> Vec<(u32, u32)>
+{
+	let mut result = Vec::new();
+	let (mut i, mut j) = (0, 0);
+	while i < a.len() && j < b.len()
+	{
+		let start = a[i].0.max(b[j].0);
+		let end = a[i].1.min(b[j].1);
+		if start <= end
+		{
+			result.push((start, end));
+		}
+		if a[i].1 < b[j].1
+		{
+			i += 1;
+		}
+		else
+		{
+			j += 1;
+		}
+	}
+	result
+}
+
+pub fn difference(a: &[(u32, u32)], b: &[(u32, u32)]) -> Vec<(u32, u32)>
+{
+	intersection(a, &complement(b))
+}
+
+pub fn contains(ranges: &[(u32, u32)], c: char) -> bool
+{
+	let c = c as u32;
+	ranges
+		.binary_search_by(|&(start, end)| {
+			if end < c
+			{
+				std::cmp::Ordering::Less
+			}
+			else if c < start
+			{
+				std::cmp::Ordering::Greater
+			}
+			else
+			{
+				std::cmp::Ordering::Equal
+			}
+		})
+		.is_ok()
+}
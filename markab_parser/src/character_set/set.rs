@@ -0,0 +1,318 @@
+use crate::character_set::{
+	ranges,
+	Property,
+};
+use std::{
+	fmt::{
+		Display,
+		Formatter,
+		Result as FmtResult,
+	},
+	ops::{
+		Range,
+		RangeInclusive,
+	},
+};
+
+#[derive(Debug, Clone)]
+enum Expr
+{
+	Class
+	{
+		not: bool,
+		chars: Vec<char>,
+		ranges: Vec<(char, char)>,
+	},
+	Property
+	{
+		not: bool,
+		property: Property,
+	},
+	Union(Box<Expr>, Box<Expr>),
+	Intersection(Box<Expr>, Box<Expr>),
+	Difference(Box<Expr>, Box<Expr>),
+	Complement(Box<Expr>),
+}
+
+#[derive(Debug, Clone)]
+pub struct CharSet
+{
+	ascii: u128,
+	ranges: Vec<(u32, u32)>,
+	expr: Expr,
+}
+
+impl CharSet
+{
+	fn compile(ranges: Vec<(u32, u32)>, expr: Expr) -> Self
+	{
+		let mut ascii = 0;
+		for &(start, end) in ranges.iter().take_while(|(start, _)| *start < 0x80)
+		{
+			for c in start..=end.min(0x7f)
+			{
+				ascii |= 1 << c;
+			}
+		}
+		Self {
+			ascii,
+			ranges,
+			expr,
+		}
+	}
+
+	pub fn new(chars: &[char], ranges: &[Range<char>]) -> Self
+	{
+		Self::compile(
+			ranges::normalize(
+				chars
+					.iter()
+					.map(|&c| (c as u32, c as u32))
+					.chain(
+						ranges
+							.iter()
+							.map(|range| (range.start as u32, range.end as u32)),
+					)
+					.collect(),
+			),
+			Expr::Class {
+				not: false,
+				chars: chars.to_vec(),
+				ranges: ranges
+					.iter()
+					.map(|range| (range.start, range.end))
+					.collect(),
+			},
+		)
+	}
+
+	pub fn range(range: RangeInclusive<char>) -> Self
+	{
+		let (start, end) = range.into_inner();
+		Self::compile(
+			ranges::normalize(vec![(start as u32, end as u32)]),
+			Expr::Class {
+				not: false,
+				chars: Vec::new(),
+				ranges: vec![(start, end)],
+			},
+		)
+	}
+
+	pub fn empty() -> Self
+	{
+		Self::new(&[], &[])
+	}
+
+	pub fn property(property: Property) -> Self
+	{
+		Self::compile(
+			property.ranges().to_vec(),
+			Expr::Property {
+				not: false,
+				property,
+			},
+		)
+	}
+
+	pub fn letter() -> Self
+	{
+		Self::property(Property::Letter)
+	}
+
+	pub fn digit() -> Self
+	{
+		Self::property(Property::Digit)
+	}
+
+	pub fn white_space() -> Self
+	{
+		Self::property(Property::WhiteSpace)
+	}
+
+	pub fn xid_start() -> Self
+	{
+		Self::property(Property::XidStart)
+	}
+
+	pub fn xid_continue() -> Self
+	{
+		Self::property(Property::XidContinue)
+	}
+
+	pub fn union(self, other: Self) -> Self
+	{
+		let ranges = ranges::union(&self.ranges, &other.ranges);
+		let expr = match (self.expr, other.expr)
+		{
+			(
+				Expr::Class {
+					not: false,
+					chars: mut chars1,
+					ranges: mut ranges1,
+				},
+				Expr::Class {
+					not: false,
+					chars: chars2,
+					ranges: ranges2,
+				},
+			) =>
+			{
+				chars1.extend(chars2);
+				ranges1.extend(ranges2);
+				Expr::Class {
+					not: false,
+					chars: chars1,
+					ranges: ranges1,
+				}
+			}
+			(expr1, expr2) => Expr::Union(Box::new(expr1), Box::new(expr2)),
+		};
+		Self::compile(ranges, expr)
+	}
+
+	pub fn intersection(self, other: Self) -> Self
+	{
+		Self::compile(
+			ranges::intersection(&self.ranges, &other.ranges),
+			Expr::Intersection(Box::new(self.expr), Box::new(other.expr)),
+		)
+	}
+
+	pub fn difference(self, other: Self) -> Self
+	{
+		Self::compile(
+			ranges::difference(&self.ranges, &other.ranges),
+			Expr::Difference(Box::new(self.expr), Box::new(other.expr)),
+		)
+	}
+
+	pub fn complement(self) -> Self
+	{
+		let expr = match self.expr
+		{
+			Expr::Class { not, chars, ranges } =>
+			{
+				Expr::Class {
+					not: !not,
+					chars,
+					ranges,
+				}
+			}
+			Expr::Property { not, property } =>
+			{
+				Expr::Property {
+					not: !not,
+					property,
+				}
+			}
+			Expr::Complement(expr) => *expr,
+			expr => Expr::Complement(Box::new(expr)),
+		};
+		Self::compile(ranges::complement(&self.ranges), expr)
+	}
+
+	pub fn contains(&self, c: char) -> bool
+	{
+		if c.is_ascii()
+		{
+			self.contains_ascii(c as u8)
+		}
+		else
+		{
+			ranges::contains(&self.ranges, c)
+		}
+	}
+
+	pub(crate) fn contains_ascii(&self, byte: u8) -> bool
+	{
+		self.ascii >> byte & 1 == 1
+	}
+}
+
+fn write_escaped(f: &mut Formatter, c: char) -> FmtResult
+{
+	match c
+	{
+		'\\' | '[' | ']' | '^' | '-' | '&' => write!(f, "\\{}", c),
+		c if c.is_control() => write!(f, "{}", c.escape_default()),
+		c => write!(f, "{}", c),
+	}
+}
+
+impl Display for Expr
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		match self
+		{
+			Expr::Class { not, chars, ranges } =>
+			{
+				write!(f, "[")?;
+				if *not
+				{
+					write!(f, "^")?;
+				}
+				for &c in chars
+				{
+					write_escaped(f, c)?;
+				}
+				for &(start, end) in ranges
+				{
+					write_escaped(f, start)?;
+					write!(f, "-")?;
+					write_escaped(f, end)?;
+				}
+				write!(f, "]")
+			}
+			Expr::Property {
+				not: false,
+				property,
+			} => write!(f, "\\p{{{}}}", property),
+			Expr::Property {
+				not: true,
+				property,
+			} => write!(f, "\\P{{{}}}", property),
+			Expr::Union(expr1, expr2) => write!(f, "[{}{}]", expr1, expr2),
+			Expr::Intersection(expr1, expr2) => write!(f, "[{}&&{}]", expr1, expr2),
+			Expr::Difference(expr1, expr2) => write!(f, "[{}--{}]", expr1, expr2),
+			Expr::Complement(expr) => write!(f, "[^{}]", expr),
+		}
+	}
+}
+
+impl Display for CharSet
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "{}", self.expr)
+	}
+}
+
+#[test]
+fn test()
+{
+	let identifier = CharSet::xid_continue().difference(CharSet::new(&['_'], &[]));
+	assert!(identifier.contains('a'));
+	assert!(identifier.contains('ß'));
+	assert!(!identifier.contains('_'));
+	assert_eq!(format!("{}", identifier), "[\\p{XID_Continue}--[_]]");
+
+	let word = CharSet::letter().union(CharSet::digit());
+	assert!(word.contains('é'));
+	assert!(word.contains('٣'));
+	assert!(!word.contains('Ⅻ'));
+	assert!(!word.contains('²'));
+	assert!(CharSet::white_space().contains('\u{3000}'));
+
+	let hex = CharSet::range('0'..='9')
+		.union(CharSet::range('a'..='f'))
+		.intersection(
+			CharSet::new(&['-', ']'], &[])
+				.union(CharSet::range('a'..='z'))
+				.complement(),
+		);
+	assert!(hex.contains('9'));
+	assert!(!hex.contains('a'));
+	assert_eq!(format!("{}", hex), "[[0-9a-f]&&[^\\-\\]a-z]]");
+}
@@ -0,0 +1,119 @@
+use crate::{
+	repetition_parser::{
+		RepetitionParser,
+		RepetitionParserError,
+		RepetitionParserRequirement,
+	},
+	Failure,
+	Nullable,
+	Parser,
+};
+use std::{
+	fmt::Debug,
+	marker::PhantomData,
+};
+
+#[derive(Debug)]
+pub struct CollectParser<'a, P, C>
+where
+	P: Parser<'a>,
+{
+	requirement: RepetitionParser<'a, P>,
+	_c: PhantomData<C>,
+}
+
+impl<'a, P, C> CollectParser<'a, P, C>
+where
+	P: Parser<'a>,
+	C: Default + Extend<P::Output>,
+{
+	pub fn new(requirement: RepetitionParser<'a, P>) -> Self
+	{
+		Self {
+			requirement,
+			_c: PhantomData,
+		}
+	}
+}
+
+impl<'a, P, C> Parser<'a> for CollectParser<'a, P, C>
+where
+	P: Parser<'a>,
+	C: Debug + Default + Extend<P::Output>,
+{
+	type Error = RepetitionParserError<'a, P>;
+	type Output = C;
+	type Requirement = RepetitionParserRequirement<'a, P>;
+	type RequirementContext = ();
+
+	fn parse(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Self::Error>
+	{
+		self.requirement
+			.parse_fold(src, pos, C::default(), |mut result, res| {
+				result.extend(Some(res));
+				result
+			})
+	}
+
+	fn skip(&self, src: &'a str, pos: &mut usize) -> Result<(), Self::Error>
+	{
+		self.requirement.skip(src, pos)
+	}
+
+	fn attempt(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Failure<'a>>
+	{
+		self.requirement
+			.attempt_fold(src, pos, C::default(), |mut result, res| {
+				result.extend(Some(res));
+				result
+			})
+	}
+
+	fn nullable(&self) -> Nullable
+	{
+		self.requirement.nullable()
+	}
+
+	fn cheap_attempt(&self) -> bool
+	{
+		self.requirement.cheap_attempt()
+	}
+
+	fn requirement(&self, _: Option<&Self::RequirementContext>) -> Self::Requirement
+	{
+		self.requirement.requirement(None)
+	}
+}
+
+#[test]
+fn test()
+{
+	use crate::{
+		character,
+		string,
+	};
+	use std::collections::BTreeSet;
+	let letters = string("a")
+		.or(string("b"))
+		.map(&|letter| letter.either_into::<&str>())
+		.one_or_more()
+		.collect::<BTreeSet<_>>();
+	let mut pos = 0;
+	assert_eq!(
+		letters.parse("abba!", &mut pos).ok(),
+		Some(vec!["a", "b"].into_iter().collect())
+	);
+	assert_eq!(pos, 4);
+	let mut pos = 0;
+	assert!(letters.parse("!", &mut pos).is_err());
+	assert_eq!(pos, 0);
+	let word = character('x').repeat(2, 3).collect::<String>();
+	let mut pos = 0;
+	assert_eq!(word.attempt("xxxx", &mut pos).ok().as_deref(), Some("xxx"));
+	assert_eq!(pos, 3);
+	let mut pos = 0;
+	assert!(word.attempt("xy", &mut pos).is_err());
+	assert_eq!(pos, 0);
+	assert!(word.skip("xxy", &mut pos).is_ok());
+	assert_eq!(pos, 2);
+}
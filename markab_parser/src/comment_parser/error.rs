@@ -0,0 +1,109 @@
+use crate::{
+	comment_parser::CommentParserRequirement,
+	Error,
+};
+use std::fmt::{
+	Display,
+	Formatter,
+	Result as FmtResult,
+};
+
+#[derive(Debug)]
+pub struct CommentParserError<'a>
+{
+	from: usize,
+	requirement: CommentParserRequirement<'a>,
+	src: &'a str,
+	unterminated: bool,
+}
+
+impl<'a> CommentParserError<'a>
+{
+	pub fn new(
+		from: usize,
+		requirement: CommentParserRequirement<'a>,
+		src: &'a str,
+		unterminated: bool,
+	) -> Self
+	{
+		Self {
+			from,
+			requirement,
+			src,
+			unterminated,
+		}
+	}
+
+	pub fn is_unterminated(&self) -> bool
+	{
+		self.unterminated
+	}
+}
+
+impl<'a> Error for CommentParserError<'a>
+{
+	fn position(&self) -> usize
+	{
+		self.from
+	}
+
+	fn from(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "{}", self.from)
+	}
+
+	fn requirement(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "{}", self.requirement)
+	}
+
+	fn result(&self, f: &mut Formatter) -> FmtResult
+	{
+		if self.unterminated
+		{
+			return write!(f, "unterminated comment found");
+		}
+		match self.src[self.from..].chars().next()
+		{
+			Some(found) => write!(f, "{:?} found", found),
+			None => write!(f, "not found"),
+		}
+	}
+
+	fn literal(&self) -> Option<&str>
+	{
+		if self.unterminated
+		{
+			None
+		}
+		else
+		{
+			Some(self.requirement.open())
+		}
+	}
+
+	fn farthest(&self) -> usize
+	{
+		if self.unterminated
+		{
+			self.src.len()
+		}
+		else
+		{
+			self.from
+		}
+	}
+
+	fn causes(&self, _: &mut Formatter, _: usize) -> FmtResult
+	{
+		Ok(())
+	}
+}
+
+impl<'a> Display for CommentParserError<'a>
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		self.print(f, 0)
+	}
+}
@@ -0,0 +1,14 @@
+mod error;
+mod parser;
+mod requirement;
+mod utility;
+
+pub use self::{
+	error::CommentParserError,
+	parser::CommentParser,
+	requirement::CommentParserRequirement,
+	utility::{
+		block_comment,
+		line_comment,
+	},
+};
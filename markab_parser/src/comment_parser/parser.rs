@@ -0,0 +1,221 @@
+use crate::{
+	comment_parser::{
+		CommentParserError,
+		CommentParserRequirement,
+	},
+	scan,
+	session,
+	Failure,
+	Needed,
+	Nullable,
+	Parser,
+};
+
+#[derive(Debug)]
+pub struct CommentParser<'a>
+{
+	open: &'a str,
+	close: Option<&'a str>,
+	nested: bool,
+}
+
+impl<'a> CommentParser<'a>
+{
+	pub fn line(open: &'a str) -> Self
+	{
+		Self {
+			open,
+			close: None,
+			nested: false,
+		}
+	}
+
+	pub fn block(open: &'a str, close: &'a str) -> Self
+	{
+		Self {
+			open,
+			close: Some(close),
+			nested: false,
+		}
+	}
+
+	pub fn nested(mut self, nested: bool) -> Self
+	{
+		self.nested = nested;
+		self
+	}
+
+	fn scan(&self, src: &str, from: usize) -> Result<usize, bool>
+	{
+		if !src[from..].starts_with(self.open)
+		{
+			session::reached_end_of_literal(src, from, self.open.chars(), |a, b| a == b);
+			return Err(false);
+		}
+		let mut at = from + self.open.len();
+		let close = match self.close
+		{
+			Some(close) => close,
+			None =>
+			{
+				return Ok(scan::find_byte(src, at, b'\n').unwrap_or_else(|| {
+					session::reached_end(src, src.len(), Needed::Unknown);
+					src.len()
+				}))
+			}
+		};
+		if !self.nested
+		{
+			return scan::find_literal(src, at, close)
+				.map(|end| end + close.len())
+				.ok_or_else(|| unterminated(src));
+		}
+		let mut depth = 1;
+		let mut next_open = scan::find_literal(src, at, self.open);
+		loop
+		{
+			let next_close = scan::find_literal(src, at, close).ok_or_else(|| unterminated(src))?;
+			match next_open
+			{
+				Some(open) if open < next_close =>
+				{
+					depth += 1;
+					at = open + self.open.len();
+					next_open = scan::find_literal(src, at, self.open);
+				}
+				_ =>
+				{
+					depth -= 1;
+					at = next_close + close.len();
+					if depth == 0
+					{
+						return Ok(at);
+					}
+					if next_open.is_some_and(|open| open < at)
+					{
+						next_open = scan::find_literal(src, at, self.open);
+					}
+				}
+			}
+		}
+	}
+}
+
+fn unterminated(src: &str) -> bool
+{
+	session::reached_end(src, src.len(), Needed::Unknown);
+	true
+}
+
+impl<'a> Parser<'a> for CommentParser<'a>
+{
+	type Error = CommentParserError<'a>;
+	type Output = &'a str;
+	type Requirement = CommentParserRequirement<'a>;
+	type RequirementContext = ();
+
+	fn parse(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Self::Error>
+	{
+		let src = session::metered(src, *pos);
+		let from = *pos;
+		match self.scan(src, from)
+		{
+			Ok(end) =>
+			{
+				*pos = end;
+				Ok(&src[from..end])
+			}
+			Err(unterminated) =>
+			{
+				session::record_failure(from, || self.requirement(None));
+				Err(CommentParserError::new(
+					from,
+					self.requirement(None),
+					src,
+					unterminated,
+				))
+			}
+		}
+	}
+
+	fn attempt(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Failure<'a>>
+	{
+		let src = session::metered(src, *pos);
+		let from = *pos;
+		match self.scan(src, from)
+		{
+			Ok(end) =>
+			{
+				*pos = end;
+				Ok(&src[from..end])
+			}
+			Err(_) =>
+			{
+				session::record_failure(from, || self.requirement(None));
+				Err(Failure::new(from))
+			}
+		}
+	}
+
+	fn next_candidate(&self, src: &'a str, from: usize) -> Option<usize>
+	{
+		scan::find_literal(src, from, self.open)
+	}
+
+	fn nullable(&self) -> Nullable
+	{
+		if self.open.is_empty()
+		{
+			Nullable::Unknown
+		}
+		else
+		{
+			Nullable::No
+		}
+	}
+
+	fn cheap_attempt(&self) -> bool
+	{
+		true
+	}
+
+	fn requirement(&self, _: Option<&Self::RequirementContext>) -> Self::Requirement
+	{
+		CommentParserRequirement::new(self.open, self.close)
+	}
+}
+
+#[test]
+fn test()
+{
+	use crate::{
+		block_comment,
+		line_comment,
+	};
+
+	let mut pos = 0;
+	assert_eq!(
+		line_comment("--").parse("-- note\nSELECT", &mut pos).ok(),
+		Some("-- note")
+	);
+	let nested = block_comment("/+", "+/").nested(true);
+	let mut pos = 0;
+	assert_eq!(
+		nested.parse("/+ a /+ b +/ c +/ d", &mut pos).ok(),
+		Some("/+ a /+ b +/ c +/")
+	);
+	let mut pos = 0;
+	assert_eq!(
+		block_comment("/*", "*/")
+			.parse("/* a /* b */ c */", &mut pos)
+			.ok(),
+		Some("/* a /* b */")
+	);
+	let mut pos = 2;
+	let err = nested.parse("x /+ a /+ b +/", &mut pos).err().unwrap();
+	assert!(err.is_unterminated());
+	assert_eq!(
+		format!("{}", err),
+		"at position 2 required /+ ... +/ but unterminated comment found.\n"
+	);
+}
@@ -0,0 +1,37 @@
+use std::fmt::{
+	Display,
+	Formatter,
+	Result as FmtResult,
+};
+
+#[derive(Debug)]
+pub struct CommentParserRequirement<'a>
+{
+	open: &'a str,
+	close: Option<&'a str>,
+}
+
+impl<'a> CommentParserRequirement<'a>
+{
+	pub fn new(open: &'a str, close: Option<&'a str>) -> Self
+	{
+		Self { open, close }
+	}
+
+	pub fn open(&self) -> &'a str
+	{
+		self.open
+	}
+}
+
+impl<'a> Display for CommentParserRequirement<'a>
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		match self.close
+		{
+			Some(close) => write!(f, "{} ... {}", self.open, close),
+			None => write!(f, "{} ...", self.open),
+		}
+	}
+}
@@ -0,0 +1,11 @@
+use crate::comment_parser::CommentParser;
+
+pub fn line_comment(open: &str) -> CommentParser<'_>
+{
+	CommentParser::line(open)
+}
+
+pub fn block_comment<'a>(open: &'a str, close: &'a str) -> CommentParser<'a>
+{
+	CommentParser::block(open, close)
+}
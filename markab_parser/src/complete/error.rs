@@ -0,0 +1,134 @@
+use crate::{
+	complete::Farthest,
+	Error,
+	Parser,
+};
+use either::{
+	Either,
+	Left,
+	Right,
+};
+use std::fmt::{
+	Display,
+	Formatter,
+	Result as FmtResult,
+};
+
+#[derive(Debug)]
+pub struct CompleteError<'a, P>
+where
+	P: Parser<'a>,
+{
+	from: usize,
+	found: Option<&'a str>,
+	cause: Either<P::Error, Farthest>,
+}
+
+impl<'a, P> CompleteError<'a, P>
+where
+	P: Parser<'a>,
+{
+	pub fn failed(err: P::Error) -> Self
+	{
+		Self {
+			from: err.position(),
+			found: None,
+			cause: Left(err),
+		}
+	}
+
+	pub fn unconsumed(from: usize, src: &'a str, farthest: Farthest) -> Self
+	{
+		let end = src[from..]
+			.char_indices()
+			.nth(1)
+			.map_or(src.len(), |(i, _)| from + i);
+		Self {
+			from,
+			found: Some(&src[from..end]),
+			cause: Right(farthest),
+		}
+	}
+
+	pub fn cause(&self) -> Either<&P::Error, &Farthest>
+	{
+		self.cause.as_ref()
+	}
+}
+
+impl<'a, P> Error for CompleteError<'a, P>
+where
+	P: Parser<'a>,
+{
+	fn position(&self) -> usize
+	{
+		self.from
+	}
+
+	fn from(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "{}", self.from)
+	}
+
+	fn requirement(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "end of input")
+	}
+
+	fn result(&self, f: &mut Formatter) -> FmtResult
+	{
+		match self.found
+		{
+			Some(found) => write!(f, "{:?} found", found),
+			None => write!(f, "failed to parse"),
+		}
+	}
+
+	fn farthest(&self) -> usize
+	{
+		match &self.cause
+		{
+			Left(err) => err.farthest(),
+			Right(farthest) => self.from.max(farthest.farthest()),
+		}
+	}
+
+	fn causes(&self, f: &mut Formatter, depth: usize) -> FmtResult
+	{
+		match &self.cause
+		{
+			Left(err) => err.print(f, depth),
+			Right(farthest) if farthest.get_from().is_some() => farthest.print(f, depth),
+			Right(_) => Ok(()),
+		}
+	}
+
+	fn for_each_cause<'s>(&'s self, f: &mut dyn FnMut(&'s dyn Error))
+	{
+		match &self.cause
+		{
+			Left(err) => f(err),
+			Right(farthest) if farthest.get_from().is_some() => f(farthest),
+			Right(_) => (),
+		}
+	}
+
+	fn delegate(&self) -> Option<&dyn Error>
+	{
+		match &self.cause
+		{
+			Left(err) => Some(err),
+			Right(_) => None,
+		}
+	}
+}
+
+impl<'a, P> Display for CompleteError<'a, P>
+where
+	P: Parser<'a>,
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		self.print(f, 0)
+	}
+}
@@ -0,0 +1,96 @@
+use crate::Error;
+use std::fmt::{
+	Display,
+	Formatter,
+	Result as FmtResult,
+};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Farthest
+{
+	from: Option<usize>,
+	expected: Vec<String>,
+}
+
+impl Farthest
+{
+	pub fn new() -> Self
+	{
+		Self {
+			from: None,
+			expected: vec![],
+		}
+	}
+
+	pub fn record<R>(&mut self, from: usize, requirement: R)
+	where
+		R: Display,
+	{
+		match self.from
+		{
+			Some(farthest) if from < farthest => return,
+			Some(farthest) if from == farthest => (),
+			_ =>
+			{
+				self.from = Some(from);
+				self.expected.clear();
+			}
+		}
+		let requirement = requirement.to_string();
+		if !self.expected.contains(&requirement)
+		{
+			self.expected.push(requirement);
+		}
+	}
+
+	pub fn get_from(&self) -> Option<usize>
+	{
+		self.from
+	}
+
+	pub fn expected(&self) -> &[String]
+	{
+		&self.expected
+	}
+}
+
+impl Error for Farthest
+{
+	fn position(&self) -> usize
+	{
+		self.from.unwrap_or(0)
+	}
+
+	fn from(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "{}", self.position())
+	}
+
+	fn requirement(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "{}", self.expected.join(" / "))
+	}
+
+	fn result(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "failed to parse")
+	}
+
+	fn farthest(&self) -> usize
+	{
+		self.position()
+	}
+
+	fn causes(&self, _: &mut Formatter, _: usize) -> FmtResult
+	{
+		Ok(())
+	}
+}
+
+impl Display for Farthest
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		self.print(f, 0)
+	}
+}
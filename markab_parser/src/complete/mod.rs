@@ -0,0 +1,7 @@
+mod error;
+mod farthest;
+
+pub use self::{
+	error::CompleteError,
+	farthest::Farthest,
+};
@@ -0,0 +1,168 @@
+use crate::diagnostic::{
+	hook::{
+		Causes,
+		Found,
+		Position,
+		Requirement,
+	},
+	Diagnostic,
+};
+use std::{
+	env,
+	fmt::{
+		Display,
+		Formatter,
+		Result as FmtResult,
+	},
+	io::IsTerminal,
+};
+
+const RESET: &str = "\x1b[0m";
+const BOLD: &str = "\x1b[1m";
+const DIM: &str = "\x1b[2m";
+const RED: &str = "\x1b[1;31m";
+const GREEN: &str = "\x1b[32m";
+const YELLOW: &str = "\x1b[33m";
+const CYAN: &str = "\x1b[36m";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorChoice
+{
+	Auto,
+	Always,
+	Never,
+}
+
+impl ColorChoice
+{
+	pub fn enabled(self, sink: &impl IsTerminal) -> bool
+	{
+		match self
+		{
+			ColorChoice::Auto => env::var_os("NO_COLOR").is_none() && sink.is_terminal(),
+			ColorChoice::Always => true,
+			ColorChoice::Never => false,
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Colored<'e>
+{
+	diagnostic: Diagnostic<'e>,
+	enabled: bool,
+}
+
+impl<'e> Colored<'e>
+{
+	pub fn new(diagnostic: Diagnostic<'e>, choice: ColorChoice, sink: &impl IsTerminal) -> Self
+	{
+		Self {
+			diagnostic,
+			enabled: choice.enabled(sink),
+		}
+	}
+
+	fn paint(&self, f: &mut Formatter, style: &str, text: impl Display) -> FmtResult
+	{
+		if self.enabled
+		{
+			write!(f, "{}{}{}", style, text, RESET)
+		}
+		else
+		{
+			write!(f, "{}", text)
+		}
+	}
+}
+
+impl<'e> Display for Colored<'e>
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		let error = self.diagnostic.error();
+		if let Some(file) = self.diagnostic.file_name()
+		{
+			self.paint(f, BOLD, format_args!("{}:", file))?;
+		}
+		self.paint(f, BOLD, format_args!("{}:", self.diagnostic.location()))?;
+		write!(f, " ")?;
+		self.paint(f, RED, "parse error")?;
+		writeln!(f)?;
+		let mut shown = error;
+		while let Some(inner) = shown.delegate()
+		{
+			shown = inner;
+		}
+		write!(f, "at position ")?;
+		self.paint(f, CYAN, Position(shown))?;
+		write!(f, " required ")?;
+		self.paint(f, GREEN, Requirement(shown))?;
+		write!(f, " but ")?;
+		self.paint(f, YELLOW, Found(shown))?;
+		writeln!(f, ".")?;
+		let causes = Causes(shown).to_string();
+		if !causes.is_empty()
+		{
+			self.paint(f, DIM, causes)?;
+		}
+		for suggestion in self.diagnostic.suggestions()
+		{
+			self.paint(f, CYAN, "hint")?;
+			writeln!(f, ": did you mean {:?}?", suggestion.literal())?;
+		}
+		Ok(())
+	}
+}
+
+#[test]
+fn test()
+{
+	use crate::{
+		string,
+		Parser,
+	};
+	let parser = string("include").or(string("import"));
+	let src = "inclde";
+	let mut pos = 0;
+	let err = parser.parse(src, &mut pos).err().unwrap();
+	let diagnostic = Diagnostic::new(&err, src);
+	let plain = format!(
+		"{}",
+		diagnostic.colored(ColorChoice::Never, &std::io::stderr())
+	);
+	assert_eq!(plain, format!("{}", diagnostic));
+	let colored = format!(
+		"{}",
+		diagnostic.colored(ColorChoice::Always, &std::io::stderr())
+	);
+	assert!(colored.contains("\x1b[32minclude / import\x1b[0m"));
+	assert!(colored.contains("\x1b[2m\tat position 0 required include"));
+
+	let plain = format!(
+		"{}",
+		diagnostic.colored(
+			ColorChoice::Auto,
+			&std::fs::File::open(concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml")).unwrap()
+		)
+	);
+	assert_eq!(plain, format!("{}", diagnostic));
+
+	let parser = string("include")
+		.or(string("import"))
+		.map(&|_| ())
+		.stringify();
+	let mut pos = 0;
+	let err = parser.parse(src, &mut pos).err().unwrap();
+	let diagnostic = Diagnostic::new(&err, src);
+	let plain = format!(
+		"{}",
+		diagnostic.colored(ColorChoice::Never, &std::io::stderr())
+	);
+	assert_eq!(plain, format!("{}", diagnostic));
+	let colored = format!(
+		"{}",
+		diagnostic.colored(ColorChoice::Always, &std::io::stderr())
+	);
+	assert!(colored.contains("\x1b[32minclude / import\x1b[0m"));
+}
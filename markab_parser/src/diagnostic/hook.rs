@@ -0,0 +1,82 @@
+use crate::Error;
+use std::fmt::{
+	Display,
+	Formatter,
+	Result as FmtResult,
+};
+
+pub struct Requirement<'e>(pub &'e dyn Error);
+
+impl<'e> Display for Requirement<'e>
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		self.0.requirement(f)
+	}
+}
+
+pub struct Found<'e>(pub &'e dyn Error);
+
+impl<'e> Display for Found<'e>
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		self.0.result(f)
+	}
+}
+
+pub fn message(error: &dyn Error) -> String
+{
+	format!("required {} but {}", Requirement(error), Found(error))
+}
+
+pub fn expected(error: &dyn Error) -> Vec<String>
+{
+	let mut expected = vec![];
+	collect_expected(error, error.farthest(), &mut expected);
+	expected
+}
+
+pub fn causes(error: &dyn Error) -> Vec<&dyn Error>
+{
+	let mut causes = vec![];
+	error.for_each_cause(&mut |cause| causes.push(cause));
+	causes
+}
+
+fn collect_expected(error: &dyn Error, farthest: usize, expected: &mut Vec<String>)
+{
+	let causes = causes(error);
+	if causes.is_empty()
+	{
+		let requirement = Requirement(error).to_string();
+		if error.farthest() == farthest && !expected.contains(&requirement)
+		{
+			expected.push(requirement);
+		}
+	}
+	for cause in causes
+	{
+		collect_expected(cause, farthest, expected);
+	}
+}
+
+pub struct Position<'e>(pub &'e dyn Error);
+
+impl<'e> Display for Position<'e>
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		self.0.from(f)
+	}
+}
+
+pub struct Causes<'e>(pub &'e dyn Error);
+
+impl<'e> Display for Causes<'e>
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		self.0.causes(f, 1)
+	}
+}
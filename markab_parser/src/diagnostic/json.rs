@@ -0,0 +1,146 @@
+use crate::{
+	diagnostic::{
+		hook::{
+			causes,
+			expected,
+			message,
+		},
+		Diagnostic,
+		Location,
+	},
+	Error,
+};
+use std::fmt::Write;
+
+pub fn to_json(diagnostics: &[Diagnostic]) -> String
+{
+	let mut out = String::new();
+	out.push('[');
+	for (i, diagnostic) in diagnostics.iter().enumerate()
+	{
+		if i != 0
+		{
+			out.push(',');
+		}
+		write_diagnostic(&mut out, diagnostic);
+	}
+	out.push(']');
+	out
+}
+
+pub(crate) fn write_string(out: &mut String, s: &str)
+{
+	out.push('"');
+	for c in s.chars()
+	{
+		match c
+		{
+			'"' => out.push_str("\\\""),
+			'\\' => out.push_str("\\\\"),
+			'\n' => out.push_str("\\n"),
+			'\r' => out.push_str("\\r"),
+			'\t' => out.push_str("\\t"),
+			c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+			c => out.push(c),
+		}
+	}
+	out.push('"');
+}
+
+pub(crate) fn range(error: &dyn Error, src: &str) -> (usize, usize)
+{
+	let start = error.position().min(src.len());
+	let mut end = error.farthest().max(start).min(src.len());
+	if end == start
+	{
+		end += src[start..].chars().next().map_or(0, char::len_utf8);
+	}
+	(start, end)
+}
+
+pub(crate) fn write_diagnostic(out: &mut String, diagnostic: &Diagnostic)
+{
+	out.push('{');
+	out.push_str("\"file\":");
+	match diagnostic.file_name()
+	{
+		Some(file) => write_string(out, file),
+		None => out.push_str("null"),
+	}
+	out.push(',');
+	write_error(out, diagnostic.error(), diagnostic.src());
+	out.push_str(",\"hints\":[");
+	for (i, suggestion) in diagnostic.suggestions().iter().enumerate()
+	{
+		if i != 0
+		{
+			out.push(',');
+		}
+		write_string(out, &format!("did you mean {:?}?", suggestion.literal()));
+	}
+	out.push_str("]}");
+}
+
+fn write_error(out: &mut String, error: &dyn Error, src: &str)
+{
+	let (start, end) = range(error, src);
+	write!(out, "\"range\":{{\"start\":{},\"end\":{}}}", start, end).unwrap();
+	out.push_str(",\"start\":");
+	write_location(out, Location::new(src, start));
+	out.push_str(",\"end\":");
+	write_location(out, Location::new(src, end));
+	out.push_str(",\"message\":");
+	write_string(out, &message(error));
+	out.push_str(",\"expected\":[");
+	for (i, expected) in expected(error).iter().enumerate()
+	{
+		if i != 0
+		{
+			out.push(',');
+		}
+		write_string(out, expected);
+	}
+	out.push_str("],\"causes\":[");
+	for (i, cause) in causes(error).into_iter().enumerate()
+	{
+		if i != 0
+		{
+			out.push(',');
+		}
+		out.push('{');
+		write_error(out, cause, src);
+		out.push('}');
+	}
+	out.push(']');
+}
+
+fn write_location(out: &mut String, location: Location)
+{
+	write!(
+		out,
+		"{{\"line\":{},\"column\":{}}}",
+		location.line(),
+		location.column()
+	)
+	.unwrap();
+}
+
+#[test]
+fn test()
+{
+	use crate::{
+		string,
+		Parser,
+	};
+	let parser = string("include").or(string("import"));
+	let src = "x\ninclde";
+	let mut pos = 2;
+	let err = parser.parse(src, &mut pos).err().unwrap();
+	let json = to_json(&[Diagnostic::new(&err, src).file("a\"b")]);
+	assert!(json.starts_with(
+		"[{\"file\":\"a\\\"b\",\"range\":{\"start\":2,\"end\":6},\"start\":{\"line\":2,\"column\":\
+		 1},\"end\":{\"line\":2,\"column\":5}"
+	));
+	assert!(json.contains("\"expected\":[\"include\"]"));
+	assert!(json.ends_with("\"hints\":[\"did you mean \\\"include\\\"?\"]}]"));
+}
@@ -0,0 +1,48 @@
+use std::fmt::{
+	Display,
+	Formatter,
+	Result as FmtResult,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Location
+{
+	line: usize,
+	column: usize,
+}
+
+impl Location
+{
+	pub fn new(src: &str, pos: usize) -> Self
+	{
+		let mut pos = pos.min(src.len());
+		while !src.is_char_boundary(pos)
+		{
+			pos -= 1;
+		}
+		let before = &src[..pos];
+		let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+		Self {
+			line: before.matches('\n').count() + 1,
+			column: before[line_start..].chars().count() + 1,
+		}
+	}
+
+	pub fn line(&self) -> usize
+	{
+		self.line
+	}
+
+	pub fn column(&self) -> usize
+	{
+		self.column
+	}
+}
+
+impl Display for Location
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "{}:{}", self.line, self.column)
+	}
+}
@@ -0,0 +1,23 @@
+mod color;
+mod hook;
+mod json;
+mod location;
+mod report;
+mod sarif;
+mod suggestion;
+
+pub use self::{
+	color::{
+		ColorChoice,
+		Colored,
+	},
+	json::to_json,
+	location::Location,
+	report::Diagnostic,
+	sarif::to_sarif,
+	suggestion::{
+		edit_distance,
+		suggest,
+		Suggestion,
+	},
+};
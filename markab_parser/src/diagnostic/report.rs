@@ -0,0 +1,114 @@
+use crate::{
+	diagnostic::{
+		json::write_diagnostic,
+		suggest,
+		ColorChoice,
+		Colored,
+		Location,
+		Suggestion,
+	},
+	Error,
+};
+use std::{
+	fmt::{
+		Debug,
+		Display,
+		Formatter,
+		Result as FmtResult,
+	},
+	io::IsTerminal,
+};
+
+#[derive(Clone, Copy)]
+pub struct Diagnostic<'e>
+{
+	error: &'e dyn Error,
+	src: &'e str,
+	file: Option<&'e str>,
+}
+
+impl<'e> Diagnostic<'e>
+{
+	pub fn new(error: &'e dyn Error, src: &'e str) -> Self
+	{
+		Self {
+			error,
+			src,
+			file: None,
+		}
+	}
+
+	pub fn file(self, file: &'e str) -> Self
+	{
+		Self {
+			file: Some(file),
+			..self
+		}
+	}
+
+	pub fn error(&self) -> &'e dyn Error
+	{
+		self.error
+	}
+
+	pub fn src(&self) -> &'e str
+	{
+		self.src
+	}
+
+	pub fn file_name(&self) -> Option<&'e str>
+	{
+		self.file
+	}
+
+	pub fn location(&self) -> Location
+	{
+		Location::new(self.src, self.error.farthest())
+	}
+
+	pub fn suggestions(&self) -> Vec<Suggestion<'e>>
+	{
+		suggest(self.error, self.src)
+	}
+
+	pub fn colored(self, choice: ColorChoice, sink: &impl IsTerminal) -> Colored<'e>
+	{
+		Colored::new(self, choice, sink)
+	}
+
+	pub fn to_json(&self) -> String
+	{
+		let mut out = String::new();
+		write_diagnostic(&mut out, self);
+		out
+	}
+}
+
+impl<'e> Debug for Diagnostic<'e>
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		f.debug_struct("Diagnostic")
+			.field("file", &self.file)
+			.field("location", &self.location())
+			.finish()
+	}
+}
+
+impl<'e> Display for Diagnostic<'e>
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		if let Some(file) = self.file
+		{
+			write!(f, "{}:", file)?;
+		}
+		writeln!(f, "{}: parse error", self.location())?;
+		self.error.print(f, 0)?;
+		for suggestion in self.suggestions()
+		{
+			writeln!(f, "hint: did you mean {:?}?", suggestion.literal())?;
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,68 @@
+use crate::diagnostic::{
+	hook::message,
+	json::{
+		range,
+		write_string,
+	},
+	Diagnostic,
+	Location,
+};
+use std::fmt::Write;
+
+pub fn to_sarif(tool: &str, diagnostics: &[Diagnostic]) -> String
+{
+	let mut out = String::new();
+	out.push_str(
+		"{\"$schema\":\"https://json.schemastore.org/sarif-2.1.0.json\",\"version\":\"2.1.0\",",
+	);
+	out.push_str("\"runs\":[{\"tool\":{\"driver\":{\"name\":");
+	write_string(&mut out, tool);
+	out.push_str(",\"rules\":[{\"id\":\"parse-error\"}]}},");
+	out.push_str("\"columnKind\":\"unicodeCodePoints\",\"results\":[");
+	for (i, diagnostic) in diagnostics.iter().enumerate()
+	{
+		if i != 0
+		{
+			out.push(',');
+		}
+		write_result(&mut out, diagnostic);
+	}
+	out.push_str("]}]}");
+	out
+}
+
+fn write_result(out: &mut String, diagnostic: &Diagnostic)
+{
+	let error = diagnostic.error();
+	let src = diagnostic.src();
+	let (start, end) = range(error, src);
+	let mut text = message(error);
+	for suggestion in diagnostic.suggestions()
+	{
+		write!(text, "; did you mean {:?}?", suggestion.literal()).unwrap();
+	}
+	out.push_str("{\"ruleId\":\"parse-error\",\"level\":\"error\",\"message\":{\"text\":");
+	write_string(out, &text);
+	out.push_str("},\"locations\":[{\"physicalLocation\":{");
+	if let Some(file) = diagnostic.file_name()
+	{
+		out.push_str("\"artifactLocation\":{\"uri\":");
+		write_string(out, file);
+		out.push_str("},");
+	}
+	let from = Location::new(src, start);
+	let to = Location::new(src, end);
+	write!(
+		out,
+		"\"region\":{{\"startLine\":{},\"startColumn\":{},\"endLine\":{},\"endColumn\":{},\"\
+		 byteOffset\":{},\"byteLength\":{}}}",
+		from.line(),
+		from.column(),
+		to.line(),
+		to.column(),
+		start,
+		end - start
+	)
+	.unwrap();
+	out.push_str("}}]}");
+}
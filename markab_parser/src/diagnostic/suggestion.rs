@@ -0,0 +1,89 @@
+use crate::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Suggestion<'e>
+{
+	literal: &'e str,
+	distance: usize,
+}
+
+impl<'e> Suggestion<'e>
+{
+	pub fn new(literal: &'e str, distance: usize) -> Self
+	{
+		Self { literal, distance }
+	}
+
+	pub fn literal(&self) -> &'e str
+	{
+		self.literal
+	}
+
+	pub fn distance(&self) -> usize
+	{
+		self.distance
+	}
+}
+
+pub fn suggest<'e>(error: &'e dyn Error, src: &str) -> Vec<Suggestion<'e>>
+{
+	let mut literals = vec![];
+	collect_literals(error, &mut literals);
+	let pos = match literals.iter().map(|(pos, _)| *pos).max()
+	{
+		Some(pos) if pos <= src.len() && src.is_char_boundary(pos) => pos,
+		_ => return vec![],
+	};
+	let mut suggestions: Vec<Suggestion> = vec![];
+	for (_, literal) in literals.into_iter().filter(|(from, _)| *from == pos)
+	{
+		if suggestions.iter().any(|s| s.literal == literal)
+		{
+			continue;
+		}
+		let distance = edit_distance(word(&src[pos..], literal), literal);
+		if 0 < distance && distance <= (literal.chars().count() / 3).max(1)
+		{
+			suggestions.push(Suggestion::new(literal, distance));
+		}
+	}
+	suggestions.sort_by_key(|suggestion| suggestion.distance);
+	suggestions
+}
+
+pub fn edit_distance(a: &str, b: &str) -> usize
+{
+	let b: Vec<char> = b.chars().collect();
+	let mut row: Vec<usize> = (0..=b.len()).collect();
+	for (i, ca) in a.chars().enumerate()
+	{
+		let mut diagonal = row[0];
+		row[0] = i + 1;
+		for (j, cb) in b.iter().enumerate()
+		{
+			let substitution = diagonal + if ca == *cb { 0 } else { 1 };
+			diagonal = row[j + 1];
+			row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+		}
+	}
+	row[b.len()]
+}
+
+fn collect_literals<'e>(error: &'e dyn Error, literals: &mut Vec<(usize, &'e str)>)
+{
+	error.for_each_literal(&mut |literal| literals.push((error.position(), literal)));
+	error.for_each_cause(&mut |cause| collect_literals(cause, literals));
+}
+
+fn word<'s>(src: &'s str, literal: &str) -> &'s str
+{
+	let is_word = |c: char| c.is_alphanumeric() || c == '_';
+	let literal_is_word = literal.chars().all(is_word);
+	let end = src
+		.char_indices()
+		.take_while(|(_, c)| !c.is_whitespace() && is_word(*c) == literal_is_word)
+		.take(literal.chars().count() + 2)
+		.last()
+		.map_or(0, |(i, c)| i + c.len_utf8());
+	&src[..end]
+}
@@ -0,0 +1,62 @@
+use crate::Error;
+use std::fmt::{
+	Display,
+	Formatter,
+	Result as FmtResult,
+};
+
+#[derive(Debug)]
+pub struct EndOfInputParserError
+{
+	from: usize,
+	found: char,
+}
+
+impl EndOfInputParserError
+{
+	pub fn new(from: usize, found: char) -> Self
+	{
+		Self { from, found }
+	}
+}
+
+impl Error for EndOfInputParserError
+{
+	fn position(&self) -> usize
+	{
+		self.from
+	}
+
+	fn from(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "{}", self.from)
+	}
+
+	fn requirement(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "end of input")
+	}
+
+	fn result(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "{:?} found", self.found)
+	}
+
+	fn farthest(&self) -> usize
+	{
+		self.from
+	}
+
+	fn causes(&self, _: &mut Formatter, _: usize) -> FmtResult
+	{
+		Ok(())
+	}
+}
+
+impl Display for EndOfInputParserError
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		self.print(f, 0)
+	}
+}
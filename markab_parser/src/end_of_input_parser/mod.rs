@@ -0,0 +1,9 @@
+mod error;
+mod parser;
+mod utility;
+
+pub use self::{
+	error::EndOfInputParserError,
+	parser::EndOfInputParser,
+	utility::end_of_input,
+};
@@ -0,0 +1,79 @@
+use crate::{
+	end_of_input_parser::EndOfInputParserError,
+	session,
+	Failure,
+	Needed,
+	Nullable,
+	Parser,
+};
+
+#[derive(Debug, Default)]
+pub struct EndOfInputParser;
+
+impl EndOfInputParser
+{
+	pub fn new() -> Self
+	{
+		EndOfInputParser
+	}
+}
+
+impl<'a> Parser<'a> for EndOfInputParser
+{
+	type Error = EndOfInputParserError;
+	type Output = ();
+	type Requirement = &'a str;
+	type RequirementContext = ();
+
+	fn parse(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Self::Error>
+	{
+		let src = session::metered(src, *pos);
+		session::lexeme(src, pos, |pos| {
+			match src[*pos..].chars().next()
+			{
+				Some(found) =>
+				{
+					session::record_failure(*pos, || "end of input");
+					Err(EndOfInputParserError::new(*pos, found))
+				}
+				None =>
+				{
+					session::reached_end(src, *pos, Needed::Unknown);
+					Ok(())
+				}
+			}
+		})
+	}
+
+	fn attempt(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Failure<'a>>
+	{
+		let src = session::metered(src, *pos);
+		session::lexeme(src, pos, |pos| {
+			if *pos < src.len()
+			{
+				session::record_failure(*pos, || "end of input");
+				Err(Failure::new(*pos))
+			}
+			else
+			{
+				session::reached_end(src, *pos, Needed::Unknown);
+				Ok(())
+			}
+		})
+	}
+
+	fn nullable(&self) -> Nullable
+	{
+		Nullable::Yes
+	}
+
+	fn cheap_attempt(&self) -> bool
+	{
+		true
+	}
+
+	fn requirement(&self, _: Option<&Self::RequirementContext>) -> Self::Requirement
+	{
+		"end of input"
+	}
+}
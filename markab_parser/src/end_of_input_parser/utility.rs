@@ -0,0 +1,6 @@
+use crate::end_of_input_parser::EndOfInputParser;
+
+pub fn end_of_input() -> EndOfInputParser
+{
+	EndOfInputParser::new()
+}
@@ -1,3 +1,8 @@
+use either::{
+	Either,
+	Left,
+	Right,
+};
 use std::fmt::{
 	Formatter,
 	Result as FmtResult,
@@ -9,25 +14,186 @@ pub trait Error
 	fn requirement(&self, f: &mut Formatter) -> FmtResult;
 	fn result(&self, f: &mut Formatter) -> FmtResult;
 	fn causes(&self, f: &mut Formatter, depth: usize) -> FmtResult;
+	fn position(&self) -> usize;
 
-	fn print(&self, f: &mut Formatter, depth: usize) -> FmtResult
+	fn farthest(&self) -> usize
 	{
-		self.print_full(f, depth)
+		self.position()
 	}
 
-	fn print_full(&self, f: &mut Formatter, depth: usize) -> FmtResult
+	fn for_each_cause<'s>(&'s self, _: &mut dyn FnMut(&'s dyn Error)) {}
+
+	fn literal(&self) -> Option<&str>
+	{
+		None
+	}
+
+	fn for_each_literal<'s>(&'s self, f: &mut dyn FnMut(&'s str))
+	{
+		if let Some(literal) = self.literal()
+		{
+			f(literal)
+		}
+	}
+
+	fn alternatives(&self) -> usize
 	{
-		for _ in 0..depth
+		1
+	}
+
+	fn delegate(&self) -> Option<&dyn Error>
+	{
+		None
+	}
+
+	fn print(&self, f: &mut Formatter, depth: usize) -> FmtResult
+	{
+		match self.delegate()
 		{
-			write!(f, "\t")?;
+			Some(err) => err.print(f, depth),
+			None => self.print_full(f, depth),
 		}
+	}
+
+	fn print_full(&self, f: &mut Formatter, depth: usize) -> FmtResult
+	{
+		indent(f, depth)?;
 		write!(f, "at position ")?;
 		self.from(f)?;
 		write!(f, " required ")?;
 		self.requirement(f)?;
 		write!(f, " but ")?;
 		self.result(f)?;
-		write!(f, ".\n")?;
+		writeln!(f, ".")?;
 		self.causes(f, depth + 1)
 	}
 }
+
+impl<L, R> Error for Either<L, R>
+where
+	L: Error,
+	R: Error,
+{
+	fn from(&self, f: &mut Formatter) -> FmtResult
+	{
+		match self
+		{
+			Left(err) => err.from(f),
+			Right(err) => err.from(f),
+		}
+	}
+
+	fn requirement(&self, f: &mut Formatter) -> FmtResult
+	{
+		match self
+		{
+			Left(err) => err.requirement(f),
+			Right(err) => err.requirement(f),
+		}
+	}
+
+	fn result(&self, f: &mut Formatter) -> FmtResult
+	{
+		match self
+		{
+			Left(err) => err.result(f),
+			Right(err) => err.result(f),
+		}
+	}
+
+	fn causes(&self, f: &mut Formatter, depth: usize) -> FmtResult
+	{
+		match self
+		{
+			Left(err) => err.causes(f, depth),
+			Right(err) => err.causes(f, depth),
+		}
+	}
+
+	fn position(&self) -> usize
+	{
+		match self
+		{
+			Left(err) => err.position(),
+			Right(err) => err.position(),
+		}
+	}
+
+	fn farthest(&self) -> usize
+	{
+		match self
+		{
+			Left(err) => err.farthest(),
+			Right(err) => err.farthest(),
+		}
+	}
+
+	fn for_each_cause<'s>(&'s self, f: &mut dyn FnMut(&'s dyn Error))
+	{
+		match self
+		{
+			Left(err) => err.for_each_cause(f),
+			Right(err) => err.for_each_cause(f),
+		}
+	}
+
+	fn literal(&self) -> Option<&str>
+	{
+		match self
+		{
+			Left(err) => err.literal(),
+			Right(err) => err.literal(),
+		}
+	}
+
+	fn for_each_literal<'s>(&'s self, f: &mut dyn FnMut(&'s str))
+	{
+		match self
+		{
+			Left(err) => err.for_each_literal(f),
+			Right(err) => err.for_each_literal(f),
+		}
+	}
+
+	fn alternatives(&self) -> usize
+	{
+		match self
+		{
+			Left(err) => err.alternatives(),
+			Right(err) => err.alternatives(),
+		}
+	}
+
+	fn delegate(&self) -> Option<&dyn Error>
+	{
+		match self
+		{
+			Left(err) => err.delegate(),
+			Right(err) => err.delegate(),
+		}
+	}
+
+	fn print(&self, f: &mut Formatter, depth: usize) -> FmtResult
+	{
+		match self
+		{
+			Left(err) => err.print(f, depth),
+			Right(err) => err.print(f, depth),
+		}
+	}
+}
+
+pub(crate) fn indent(f: &mut Formatter, depth: usize) -> FmtResult
+{
+	for _ in 0..depth
+	{
+		write!(f, "\t")?;
+	}
+	Ok(())
+}
+
+pub(crate) fn print_pruned(f: &mut Formatter, depth: usize) -> FmtResult
+{
+	indent(f, depth)?;
+	writeln!(f, "(some causes were pruned)")
+}
@@ -0,0 +1,63 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ErrorLimits
+{
+	max_depth: usize,
+	max_alternatives: usize,
+	farthest_only: bool,
+}
+
+impl ErrorLimits
+{
+	pub fn new() -> Self
+	{
+		Self {
+			max_depth: usize::MAX,
+			max_alternatives: usize::MAX,
+			farthest_only: false,
+		}
+	}
+
+	pub fn max_depth(self, max_depth: usize) -> Self
+	{
+		Self { max_depth, ..self }
+	}
+
+	pub fn max_alternatives(self, max_alternatives: usize) -> Self
+	{
+		Self {
+			max_alternatives: max_alternatives.max(1),
+			..self
+		}
+	}
+
+	pub fn farthest_only(self, farthest_only: bool) -> Self
+	{
+		Self {
+			farthest_only,
+			..self
+		}
+	}
+
+	pub fn get_max_depth(&self) -> usize
+	{
+		self.max_depth
+	}
+
+	pub fn get_max_alternatives(&self) -> usize
+	{
+		self.max_alternatives
+	}
+
+	pub fn is_farthest_only(&self) -> bool
+	{
+		self.farthest_only
+	}
+}
+
+impl Default for ErrorLimits
+{
+	fn default() -> Self
+	{
+		Self::new()
+	}
+}
@@ -0,0 +1,58 @@
+use std::fmt::{
+	Display,
+	Formatter,
+	Result as FmtResult,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Failure<'a>
+{
+	from: usize,
+	rule: Option<&'a str>,
+}
+
+impl<'a> Failure<'a>
+{
+	pub fn new(from: usize) -> Self
+	{
+		Self { from, rule: None }
+	}
+
+	pub fn named(from: usize, rule: &'a str) -> Self
+	{
+		Self {
+			from,
+			rule: Some(rule),
+		}
+	}
+
+	pub fn at(self, from: usize) -> Self
+	{
+		Self {
+			from,
+			rule: self.rule,
+		}
+	}
+
+	pub fn from(&self) -> usize
+	{
+		self.from
+	}
+
+	pub fn rule(&self) -> Option<&'a str>
+	{
+		self.rule
+	}
+}
+
+impl<'a> Display for Failure<'a>
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		match self.rule
+		{
+			Some(rule) => write!(f, "at position {} failed to parse {}.", self.from, rule),
+			None => write!(f, "at position {} failed to parse.", self.from),
+		}
+	}
+}
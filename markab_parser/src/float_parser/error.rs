@@ -0,0 +1,81 @@
+use crate::{
+	float_parser::FloatParserRequirement,
+	Error,
+};
+use std::fmt::{
+	Display,
+	Formatter,
+	Result as FmtResult,
+};
+
+#[derive(Debug)]
+pub struct FloatParserError
+{
+	from: usize,
+	requirement: FloatParserRequirement,
+	at: usize,
+	found: Option<char>,
+}
+
+impl FloatParserError
+{
+	pub fn new(
+		from: usize,
+		requirement: FloatParserRequirement,
+		at: usize,
+		found: Option<char>,
+	) -> Self
+	{
+		Self {
+			from,
+			requirement,
+			at,
+			found,
+		}
+	}
+}
+
+impl Error for FloatParserError
+{
+	fn position(&self) -> usize
+	{
+		self.from
+	}
+
+	fn from(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "{}", self.from)
+	}
+
+	fn requirement(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "{}", self.requirement)
+	}
+
+	fn result(&self, f: &mut Formatter) -> FmtResult
+	{
+		match self.found
+		{
+			Some(found) => write!(f, "{:?} found", found),
+			None => write!(f, "not found"),
+		}
+	}
+
+	fn farthest(&self) -> usize
+	{
+		self.at
+	}
+
+	fn causes(&self, _: &mut Formatter, _: usize) -> FmtResult
+	{
+		Ok(())
+	}
+}
+
+impl Display for FloatParserError
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		self.print(f, 0)
+	}
+}
@@ -0,0 +1,10 @@
+use std::{
+	fmt::Debug,
+	str::FromStr,
+};
+
+pub trait Float: Copy + Debug + FromStr {}
+
+impl Float for f32 {}
+
+impl Float for f64 {}
@@ -0,0 +1,15 @@
+mod error;
+mod float;
+mod parser;
+mod requirement;
+mod syntax;
+mod utility;
+
+pub use self::{
+	error::FloatParserError,
+	float::Float,
+	parser::FloatParser,
+	requirement::FloatParserRequirement,
+	syntax::FloatSyntax,
+	utility::float,
+};
@@ -0,0 +1,184 @@
+use crate::{
+	float_parser::{
+		Float,
+		FloatParserError,
+		FloatParserRequirement,
+		FloatSyntax,
+	},
+	session,
+	Failure,
+	Needed,
+	Nullable,
+	Parser,
+};
+use std::{
+	any::type_name,
+	marker::PhantomData,
+	str,
+};
+
+const STACK_BUFFER_SIZE: usize = 64;
+
+#[derive(Debug)]
+pub struct FloatParser<'a, T>
+where
+	T: Float,
+{
+	syntax: FloatSyntax,
+	sign: bool,
+	_a: PhantomData<&'a T>,
+}
+
+impl<'a, T> FloatParser<'a, T>
+where
+	T: Float,
+{
+	pub fn new(syntax: FloatSyntax) -> Self
+	{
+		Self {
+			syntax,
+			sign: true,
+			_a: PhantomData,
+		}
+	}
+
+	pub fn sign(mut self, sign: bool) -> Self
+	{
+		self.sign = sign;
+		self
+	}
+
+	fn scan(&self, src: &str, from: usize) -> Result<(T, usize), usize>
+	{
+		let end = self
+			.syntax
+			.scan(src, from, self.sign)
+			.inspect_err(|&at| session::reached_end(src, at, Needed::Unknown))?;
+		session::reached_end(src, end, Needed::Unknown);
+		let text = &src[from..end];
+		let value = if !text.contains('_')
+		{
+			text.parse()
+		}
+		else if text.len() <= STACK_BUFFER_SIZE
+		{
+			let mut buffer = [0; STACK_BUFFER_SIZE];
+			let mut len = 0;
+			for &byte in text.as_bytes().iter().filter(|&&byte| byte != b'_')
+			{
+				buffer[len] = byte;
+				len += 1;
+			}
+			str::from_utf8(&buffer[..len]).map_err(|_| from)?.parse()
+		}
+		else
+		{
+			// Only numbers with separators longer than the stack buffer allocate.
+			text.replace('_', "").parse()
+		};
+		value.map(|value| (value, end)).map_err(|_| from)
+	}
+}
+
+impl<'a, T> Parser<'a> for FloatParser<'a, T>
+where
+	T: Float,
+{
+	type Error = FloatParserError;
+	type Output = T;
+	type Requirement = FloatParserRequirement;
+	type RequirementContext = ();
+
+	fn parse(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Self::Error>
+	{
+		let src = session::metered(src, *pos);
+		session::lexeme(src, pos, |pos| {
+			let from = *pos;
+			match self.scan(src, from)
+			{
+				Ok((value, end)) =>
+				{
+					*pos = end;
+					Ok(value)
+				}
+				Err(at) =>
+				{
+					session::record_failure(from, || self.requirement(None));
+					Err(FloatParserError::new(
+						from,
+						self.requirement(None),
+						at,
+						src[at..].chars().next(),
+					))
+				}
+			}
+		})
+	}
+
+	fn attempt(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Failure<'a>>
+	{
+		let src = session::metered(src, *pos);
+		session::lexeme(src, pos, |pos| {
+			let from = *pos;
+			match self.scan(src, from)
+			{
+				Ok((value, end)) =>
+				{
+					*pos = end;
+					Ok(value)
+				}
+				Err(_) =>
+				{
+					session::record_failure(from, || self.requirement(None));
+					Err(Failure::new(from))
+				}
+			}
+		})
+	}
+
+	fn nullable(&self) -> Nullable
+	{
+		Nullable::No
+	}
+
+	fn cheap_attempt(&self) -> bool
+	{
+		true
+	}
+
+	fn requirement(&self, _: Option<&Self::RequirementContext>) -> Self::Requirement
+	{
+		FloatParserRequirement::new(type_name::<T>(), self.syntax)
+	}
+}
+
+#[test]
+fn test()
+{
+	use crate::float;
+
+	let rust = float::<f64>(FloatSyntax::Rust);
+	let json = float::<f64>(FloatSyntax::Json);
+	let mut pos = 0;
+	assert_eq!(rust.parse("1_000.5e-1_0,", &mut pos).ok(), Some(1000.5e-10));
+	assert_eq!(pos, 12);
+	let long = format!("{}.5", "1_0".repeat(STACK_BUFFER_SIZE));
+	let mut pos = 0;
+	assert_eq!(
+		rust.parse(&long, &mut pos).ok(),
+		long.replace('_', "").parse().ok()
+	);
+	let mut pos = 0;
+	assert_eq!(rust.parse("1..2", &mut pos).ok(), Some(1.0));
+	assert_eq!(pos, 1);
+	let mut pos = 0;
+	assert_eq!(json.parse("-0.25E+2", &mut pos).ok(), Some(-25.0));
+	let mut pos = 0;
+	assert_eq!(json.parse("012", &mut pos).ok(), Some(0.0));
+	assert_eq!(pos, 1);
+	let mut pos = 0;
+	assert_eq!(
+		format!("{}", json.parse("+1", &mut pos).err().unwrap()),
+		"at position 0 required f64 in JSON syntax but '+' found.\n"
+	);
+}
@@ -0,0 +1,34 @@
+use crate::float_parser::FloatSyntax;
+use std::fmt::{
+	Display,
+	Formatter,
+	Result as FmtResult,
+};
+
+#[derive(Debug)]
+pub struct FloatParserRequirement
+{
+	name: &'static str,
+	syntax: FloatSyntax,
+}
+
+impl FloatParserRequirement
+{
+	pub fn new(name: &'static str, syntax: FloatSyntax) -> Self
+	{
+		Self { name, syntax }
+	}
+
+	pub fn syntax(&self) -> FloatSyntax
+	{
+		self.syntax
+	}
+}
+
+impl Display for FloatParserRequirement
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "{} in {} syntax", self.name, self.syntax)
+	}
+}
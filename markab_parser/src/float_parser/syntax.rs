@@ -0,0 +1,90 @@
+use std::fmt::{
+	Display,
+	Formatter,
+	Result as FmtResult,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FloatSyntax
+{
+	Rust,
+	Json,
+}
+
+impl FloatSyntax
+{
+	pub(crate) fn scan(self, src: &str, from: usize, sign: bool) -> Result<usize, usize>
+	{
+		let bytes = src.as_bytes();
+		let separators = self == FloatSyntax::Rust;
+		let digits = |mut at: usize, leading_separator: bool| {
+			let start = at;
+			let mut count = 0;
+			while let Some(&byte) = bytes.get(at)
+			{
+				if byte.is_ascii_digit()
+				{
+					count += 1;
+				}
+				else if !(separators && byte == b'_' && (leading_separator || at != start))
+				{
+					break;
+				}
+				at += 1;
+			}
+			(count, at)
+		};
+		let mut at = from;
+		if sign
+		{
+			match (self, bytes.get(at))
+			{
+				(_, Some(b'-')) | (FloatSyntax::Rust, Some(b'+')) => at += 1,
+				_ => (),
+			}
+		}
+		at = match (self, bytes.get(at))
+		{
+			(FloatSyntax::Json, Some(b'0')) => at + 1,
+			_ =>
+			{
+				let (count, end) = digits(at, false);
+				if count == 0
+				{
+					return Err(at);
+				}
+				end
+			}
+		};
+		if bytes.get(at) == Some(&b'.') && bytes.get(at + 1).is_some_and(u8::is_ascii_digit)
+		{
+			at = digits(at + 1, false).1;
+		}
+		if let Some(b'e') | Some(b'E') = bytes.get(at)
+		{
+			let mut exponent = at + 1;
+			if let Some(b'+') | Some(b'-') = bytes.get(exponent)
+			{
+				exponent += 1;
+			}
+			let (count, end) = digits(exponent, true);
+			if count != 0
+			{
+				at = end;
+			}
+		}
+		Ok(at)
+	}
+}
+
+impl Display for FloatSyntax
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		match self
+		{
+			FloatSyntax::Rust => write!(f, "Rust"),
+			FloatSyntax::Json => write!(f, "JSON"),
+		}
+	}
+}
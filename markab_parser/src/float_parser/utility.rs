@@ -0,0 +1,12 @@
+use crate::float_parser::{
+	Float,
+	FloatParser,
+	FloatSyntax,
+};
+
+pub fn float<'a, T>(syntax: FloatSyntax) -> FloatParser<'a, T>
+where
+	T: Float,
+{
+	FloatParser::new(syntax)
+}
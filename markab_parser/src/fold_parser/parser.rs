@@ -0,0 +1,124 @@
+use crate::{
+	repetition_parser::{
+		RepetitionParser,
+		RepetitionParserError,
+		RepetitionParserRequirement,
+	},
+	Failure,
+	Nullable,
+	Parser,
+};
+use std::fmt::{
+	Debug,
+	Formatter,
+	Result as FmtResult,
+};
+
+pub struct FoldParser<'a, P, A>
+where
+	P: 'a + Parser<'a>,
+{
+	requirement: RepetitionParser<'a, P>,
+	init: A,
+	folder: &'a dyn Fn(A, P::Output) -> A,
+}
+
+impl<'a, P, A> FoldParser<'a, P, A>
+where
+	P: Parser<'a>,
+	A: Clone,
+{
+	pub fn new(
+		requirement: RepetitionParser<'a, P>,
+		init: A,
+		folder: &'a dyn Fn(A, P::Output) -> A,
+	) -> Self
+	{
+		Self {
+			requirement,
+			init,
+			folder,
+		}
+	}
+}
+
+impl<'a, P, A> Debug for FoldParser<'a, P, A>
+where
+	P: Parser<'a>,
+	A: Debug,
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		f.debug_struct("FoldParser")
+			.field("requirement", &self.requirement)
+			.field("init", &self.init)
+			.field("folder", &"..")
+			.finish()
+	}
+}
+
+impl<'a, P, A> Parser<'a> for FoldParser<'a, P, A>
+where
+	P: Parser<'a>,
+	A: Clone + Debug,
+{
+	type Error = RepetitionParserError<'a, P>;
+	type Output = A;
+	type Requirement = RepetitionParserRequirement<'a, P>;
+	type RequirementContext = ();
+
+	fn parse(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Self::Error>
+	{
+		self.requirement
+			.parse_fold(src, pos, self.init.clone(), self.folder)
+	}
+
+	fn skip(&self, src: &'a str, pos: &mut usize) -> Result<(), Self::Error>
+	{
+		self.requirement.skip(src, pos)
+	}
+
+	fn attempt(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Failure<'a>>
+	{
+		self.requirement
+			.attempt_fold(src, pos, self.init.clone(), self.folder)
+	}
+
+	fn nullable(&self) -> Nullable
+	{
+		self.requirement.nullable()
+	}
+
+	fn cheap_attempt(&self) -> bool
+	{
+		self.requirement.cheap_attempt()
+	}
+
+	fn requirement(&self, _: Option<&Self::RequirementContext>) -> Self::Requirement
+	{
+		self.requirement.requirement(None)
+	}
+}
+
+#[test]
+fn test()
+{
+	use crate::{
+		character_class,
+		string,
+	};
+	const DIGITS: [char; 10] = ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'];
+	let digit = character_class(false, &DIGITS, &[]);
+	let sum = digit
+		.map(&|d| d.parse::<u32>().unwrap())
+		.one_or_more()
+		.fold(0, &|sum, d| sum + d);
+	let mut pos = 0;
+	assert_eq!(sum.parse("1234x", &mut pos).ok(), Some(10));
+	assert_eq!(pos, 4);
+	let count = character_class(false, &DIGITS, &[]).zero_or_more().count();
+	pos = 0;
+	assert_eq!(count.parse("1234x", &mut pos).ok(), Some(4));
+	let count = string("ab").repeat(1, 3).count();
+	assert_eq!(format!("{}", count.requirement(None)), "ab{1,3}");
+}
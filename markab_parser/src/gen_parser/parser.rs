@@ -3,6 +3,9 @@ use crate::{
 		GenParserError,
 		GenParserRequirement,
 	},
+	session,
+	Failure,
+	Nullable,
 	Parser,
 };
 use either::{
@@ -21,7 +24,7 @@ where
 	P2: Parser<'a>,
 {
 	requirement: P1,
-	generator: &'a Fn(&P1::Output) -> P2,
+	generator: &'a dyn Fn(&P1::Output) -> P2,
 }
 
 impl<'a, P1, P2> GenParser<'a, P1, P2>
@@ -29,7 +32,7 @@ where
 	P1: Parser<'a>,
 	P2: Parser<'a>,
 {
-	pub fn new(requirement: P1, generator: &'a Fn(&P1::Output) -> P2) -> Self
+	pub fn new(requirement: P1, generator: &'a dyn Fn(&P1::Output) -> P2) -> Self
 	{
 		Self {
 			requirement,
@@ -65,12 +68,15 @@ where
 	fn parse(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Self::Error>
 	{
 		let from = *pos;
+		let checkpoint = session::checkpoint();
 		let res1 = self
 			.requirement
 			.parse(src, pos)
 			.map_err(|err| GenParserError::new(from, self.requirement(None), Left(err)))?;
 		let parser = (self.generator)(&res1);
 		let res2 = parser.parse(src, pos).map_err(|err| {
+			*pos = from;
+			session::rollback(checkpoint);
 			GenParserError::new(from, self.requirement(Some(&parser)), Right(err))
 		})?;
 		Ok((res1, res2))
@@ -79,15 +85,40 @@ where
 	fn skip(&self, src: &'a str, pos: &mut usize) -> Result<(), Self::Error>
 	{
 		let from = *pos;
+		let checkpoint = session::checkpoint();
 		let res1 = match self.requirement.parse(src, pos)
 		{
 			Ok(ok) => ok,
 			Err(err) => return Err(GenParserError::new(from, self.requirement(None), Left(err))),
 		};
 		let parser = (self.generator)(&res1);
-		parser
-			.skip(src, pos)
-			.map_err(|err| GenParserError::new(from, self.requirement(None), Right(err)))
+		parser.skip(src, pos).map_err(|err| {
+			*pos = from;
+			session::rollback(checkpoint);
+			GenParserError::new(from, self.requirement(None), Right(err))
+		})
+	}
+
+	fn attempt(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Failure<'a>>
+	{
+		let from = *pos;
+		let checkpoint = session::checkpoint();
+		let res1 = self
+			.requirement
+			.attempt(src, pos)
+			.map_err(|failure| failure.at(from))?;
+		let parser = (self.generator)(&res1);
+		let res2 = parser.attempt(src, pos).map_err(|failure| {
+			*pos = from;
+			session::rollback(checkpoint);
+			failure.at(from)
+		})?;
+		Ok((res1, res2))
+	}
+
+	fn nullable(&self) -> Nullable
+	{
+		self.requirement.nullable().then(Nullable::Unknown)
 	}
 
 	fn requirement(&self, context: Option<&Self::RequirementContext>) -> Self::Requirement
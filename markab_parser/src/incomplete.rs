@@ -0,0 +1,134 @@
+use crate::Error;
+use std::fmt::{
+	Display,
+	Formatter,
+	Result as FmtResult,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Needed
+{
+	Unknown,
+	Size(usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Incomplete
+{
+	offset: usize,
+	needed: Needed,
+}
+
+impl Incomplete
+{
+	pub fn new(offset: usize, needed: Needed) -> Self
+	{
+		Self { offset, needed }
+	}
+
+	pub fn offset(&self) -> usize
+	{
+		self.offset
+	}
+
+	pub fn needed(&self) -> Needed
+	{
+		self.needed
+	}
+}
+
+impl Error for Incomplete
+{
+	fn position(&self) -> usize
+	{
+		self.offset
+	}
+
+	fn from(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "{}", self.offset)
+	}
+
+	fn requirement(&self, f: &mut Formatter) -> FmtResult
+	{
+		match self.needed
+		{
+			Needed::Unknown => write!(f, "more input"),
+			Needed::Size(1) => write!(f, "1 more byte"),
+			Needed::Size(size) => write!(f, "{} more bytes", size),
+		}
+	}
+
+	fn result(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "reached the end of available input")
+	}
+
+	fn farthest(&self) -> usize
+	{
+		self.offset
+	}
+
+	fn causes(&self, _: &mut Formatter, _: usize) -> FmtResult
+	{
+		Ok(())
+	}
+}
+
+impl Display for Incomplete
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		self.print(f, 0)
+	}
+}
+
+#[test]
+fn test()
+{
+	use crate::{
+		string,
+		take_while,
+		Interrupted,
+		Parser,
+		Session,
+	};
+	use either::{
+		Left,
+		Right,
+	};
+
+	let session = Session::new();
+	let parser = string("hello");
+	let mut pos = 0;
+	assert_eq!(
+		session
+			.parse_partial(&parser, "hel", &mut pos)
+			.err()
+			.and_then(|err| err.right()),
+		Some(Interrupted::Incomplete(Incomplete::new(3, Needed::Size(2))))
+	);
+	assert_eq!(pos, 0);
+	assert!(matches!(
+		session.parse_partial(&parser, "help", &mut pos),
+		Err(Left(_))
+	));
+	assert_eq!(
+		session.parse_partial(&parser, "hello", &mut pos).ok(),
+		Some("hello")
+	);
+
+	let line = take_while("word", &char::is_alphabetic, 1, usize::MAX).and_then(string("\n"));
+	let mut pos = 0;
+	assert!(matches!(
+		session.parse_partial(&line, "abc", &mut pos),
+		Err(Right(Interrupted::Incomplete(incomplete))) if incomplete.needed() == Needed::Unknown
+	));
+	let mut pos = 0;
+	assert!(session.parse_partial(&line, "abc\n", &mut pos).is_ok());
+	assert_eq!(pos, 4);
+	assert_eq!(
+		format!("{}", Incomplete::new(3, Needed::Size(2))),
+		"at position 3 required 2 more bytes but reached the end of available input.\n"
+	);
+}
@@ -0,0 +1,89 @@
+use crate::{
+	indentation::{
+		measure,
+		IndentationError,
+		IndentationFound,
+		IndentationRequirement,
+	},
+	session,
+	Failure,
+	Nullable,
+	Parser,
+};
+use std::marker::PhantomData;
+
+#[derive(Debug)]
+pub struct DedentParser<'a>
+{
+	_a: PhantomData<&'a ()>,
+}
+
+impl<'a> DedentParser<'a>
+{
+	pub fn new() -> Self
+	{
+		Self { _a: PhantomData }
+	}
+
+	fn scan(&self, src: &'a str, from: usize) -> Result<(), IndentationError>
+	{
+		let level = session::indent_level();
+		let (at, found) = match measure(src, from)
+		{
+			Ok((_, width)) if width < level && session::is_enclosing_indent(width) => return Ok(()),
+			Ok((at, width)) if width < level => (at, IndentationFound::Inconsistent(width)),
+			Ok((at, width)) => (at, IndentationFound::Width(width)),
+			Err(failure) => failure,
+		};
+		session::record_failure(at, || IndentationRequirement::Dedent(level));
+		Err(IndentationError::new(
+			from,
+			IndentationRequirement::Dedent(level),
+			at,
+			found,
+		))
+	}
+}
+
+impl<'a> Default for DedentParser<'a>
+{
+	fn default() -> Self
+	{
+		Self::new()
+	}
+}
+
+impl<'a> Parser<'a> for DedentParser<'a>
+{
+	type Error = IndentationError;
+	type Output = ();
+	type Requirement = IndentationRequirement;
+	type RequirementContext = ();
+
+	fn parse(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Self::Error>
+	{
+		let src = session::metered(src, *pos);
+		self.scan(src, *pos)
+	}
+
+	fn attempt(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Failure<'a>>
+	{
+		let src = session::metered(src, *pos);
+		self.scan(src, *pos).map_err(|_| Failure::new(*pos))
+	}
+
+	fn nullable(&self) -> Nullable
+	{
+		Nullable::Yes
+	}
+
+	fn cheap_attempt(&self) -> bool
+	{
+		true
+	}
+
+	fn requirement(&self, _: Option<&Self::RequirementContext>) -> Self::Requirement
+	{
+		IndentationRequirement::Dedent(session::indent_level())
+	}
+}
@@ -0,0 +1,216 @@
+use crate::{
+	indentation::IndentationRequirement,
+	Error,
+	Parser,
+};
+use either::{
+	Either,
+	Left,
+	Right,
+};
+use std::fmt::{
+	Display,
+	Formatter,
+	Result as FmtResult,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndentationFound
+{
+	Width(usize),
+	Inconsistent(usize),
+	Tab,
+	NotLineStart,
+}
+
+impl Display for IndentationFound
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		match self
+		{
+			IndentationFound::Width(width) => write!(f, "indentation of {} found", width),
+			IndentationFound::Inconsistent(width) =>
+			{
+				write!(
+					f,
+					"indentation of {} matching no enclosing block found",
+					width
+				)
+			}
+			IndentationFound::Tab => write!(f, "tab found"),
+			IndentationFound::NotLineStart => write!(f, "middle of line found"),
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct IndentationError
+{
+	from: usize,
+	requirement: IndentationRequirement,
+	at: usize,
+	found: IndentationFound,
+}
+
+impl IndentationError
+{
+	pub fn new(
+		from: usize,
+		requirement: IndentationRequirement,
+		at: usize,
+		found: IndentationFound,
+	) -> Self
+	{
+		Self {
+			from,
+			requirement,
+			at,
+			found,
+		}
+	}
+
+	pub fn found(&self) -> IndentationFound
+	{
+		self.found
+	}
+}
+
+impl Error for IndentationError
+{
+	fn position(&self) -> usize
+	{
+		self.from
+	}
+
+	fn from(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "{}", self.from)
+	}
+
+	fn requirement(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "{}", self.requirement)
+	}
+
+	fn result(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "{}", self.found)
+	}
+
+	fn farthest(&self) -> usize
+	{
+		self.at
+	}
+
+	fn causes(&self, _: &mut Formatter, _: usize) -> FmtResult
+	{
+		Ok(())
+	}
+}
+
+impl Display for IndentationError
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		self.print(f, 0)
+	}
+}
+
+#[derive(Debug)]
+pub struct IndentParserError<'a, P>
+where
+	P: Parser<'a>,
+{
+	from: usize,
+	requirement: IndentationRequirement,
+	cause: Either<IndentationError, P::Error>,
+}
+
+impl<'a, P> IndentParserError<'a, P>
+where
+	P: Parser<'a>,
+{
+	pub fn new(
+		from: usize,
+		requirement: IndentationRequirement,
+		cause: Either<IndentationError, P::Error>,
+	) -> Self
+	{
+		Self {
+			from,
+			requirement,
+			cause,
+		}
+	}
+
+	pub fn cause(&self) -> Either<&IndentationError, &P::Error>
+	{
+		self.cause.as_ref()
+	}
+}
+
+impl<'a, P> Error for IndentParserError<'a, P>
+where
+	P: Parser<'a>,
+{
+	fn position(&self) -> usize
+	{
+		self.from
+	}
+
+	fn from(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "{}", self.from)
+	}
+
+	fn requirement(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "{}", self.requirement)
+	}
+
+	fn result(&self, f: &mut Formatter) -> FmtResult
+	{
+		match &self.cause
+		{
+			Left(err) => err.result(f),
+			Right(_) => write!(f, "failed to parse"),
+		}
+	}
+
+	fn farthest(&self) -> usize
+	{
+		match &self.cause
+		{
+			Left(err) => err.farthest(),
+			Right(err) => err.farthest(),
+		}
+	}
+
+	fn causes(&self, f: &mut Formatter, depth: usize) -> FmtResult
+	{
+		match &self.cause
+		{
+			Left(_) => Ok(()),
+			Right(err) => err.print(f, depth),
+		}
+	}
+
+	fn for_each_cause<'s>(&'s self, f: &mut dyn FnMut(&'s dyn Error))
+	{
+		if let Right(err) = &self.cause
+		{
+			f(err)
+		}
+	}
+}
+
+impl<'a, P> Display for IndentParserError<'a, P>
+where
+	P: Parser<'a>,
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		self.print(f, 0)
+	}
+}
@@ -0,0 +1,161 @@
+use crate::{
+	indentation::{
+		measure,
+		IndentParserError,
+		IndentationError,
+		IndentationFound,
+		IndentationRequirement,
+	},
+	session::{
+		self,
+		IndentGuard,
+	},
+	Failure,
+	Nullable,
+	Parser,
+};
+use either::{
+	Left,
+	Right,
+};
+use std::marker::PhantomData;
+
+#[derive(Debug)]
+pub struct IndentParser<'a, P>
+where
+	P: Parser<'a>,
+{
+	block: P,
+	_a: PhantomData<&'a ()>,
+}
+
+impl<'a, P> IndentParser<'a, P>
+where
+	P: Parser<'a>,
+{
+	pub fn new(block: P) -> Self
+	{
+		Self {
+			block,
+			_a: PhantomData,
+		}
+	}
+
+	fn open(&self, src: &'a str, from: usize) -> Result<(usize, IndentGuard), IndentationError>
+	{
+		let level = session::indent_level();
+		match measure(src, from)
+		{
+			Ok((at, width)) if width > level => Ok((at, IndentGuard::push(width))),
+			Ok((at, width)) => Err((at, IndentationFound::Width(width))),
+			Err(failure) => Err(failure),
+		}
+		.map_err(|(at, found)| {
+			session::record_failure(at, || IndentationRequirement::Indent(level));
+			IndentationError::new(from, IndentationRequirement::Indent(level), at, found)
+		})
+	}
+}
+
+impl<'a, P> Parser<'a> for IndentParser<'a, P>
+where
+	P: Parser<'a>,
+{
+	type Error = IndentParserError<'a, P>;
+	type Output = P::Output;
+	type Requirement = IndentationRequirement;
+	type RequirementContext = ();
+
+	fn parse(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Self::Error>
+	{
+		let src = session::metered(src, *pos);
+		let from = *pos;
+		let requirement = self.requirement(None);
+		let (at, _guard) = self
+			.open(src, from)
+			.map_err(|err| IndentParserError::new(from, requirement, Left(err)))?;
+		*pos = at;
+		self.block.parse(src, pos).map_err(|err| {
+			*pos = from;
+			IndentParserError::new(from, requirement, Right(err))
+		})
+	}
+
+	fn attempt(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Failure<'a>>
+	{
+		let src = session::metered(src, *pos);
+		let from = *pos;
+		let (at, _guard) = self.open(src, from).map_err(|_| Failure::new(from))?;
+		*pos = at;
+		self.block.attempt(src, pos).map_err(|failure| {
+			*pos = from;
+			failure.at(from)
+		})
+	}
+
+	fn nullable(&self) -> Nullable
+	{
+		Nullable::No
+	}
+
+	fn cheap_attempt(&self) -> bool
+	{
+		self.block.cheap_attempt()
+	}
+
+	fn requirement(&self, _: Option<&Self::RequirementContext>) -> Self::Requirement
+	{
+		IndentationRequirement::Indent(session::indent_level())
+	}
+}
+
+#[test]
+fn test()
+{
+	use crate::{
+		dedent,
+		indent,
+		indentation::TabPolicy,
+		same_indent,
+		string,
+		take_while,
+		Interrupted,
+		Session,
+	};
+
+	let line = || take_while("line", &|c| c != '\n', 1, usize::MAX).and_then(string("\n"));
+	let block = line()
+		.and_then(same_indent().and_then(line()).zero_or_more())
+		.and_then(dedent());
+	let parser = line().and_then(indent(block));
+	let mut pos = 0;
+	assert!(parser.parse("a:\n  b\n\n  c\nd\n", &mut pos).is_ok());
+	assert_eq!(pos, 12);
+	let mut pos = 0;
+	assert!(parser.parse("a:\n\tb\n        c\n", &mut pos).is_ok());
+	let mut pos = 0;
+	let err = parser.parse("a:\n    b\n  c\n", &mut pos).err().unwrap();
+	assert!(format!("{}", err).ends_with(
+		"\t\t\tat position 9 required indentation shallower than 4 but indentation of 2 matching \
+		 no enclosing block found.\n"
+	));
+	let session = Session::new().tab_policy(TabPolicy::Reject);
+	let mut pos = 0;
+	assert!(session.parse(&parser, "a:\n\tb\n", &mut pos).is_err());
+
+	let partial = |src| {
+		let mut pos = 0;
+		matches!(
+			Session::new().parse_partial(&parser, src, &mut pos),
+			Err(Right(Interrupted::Incomplete(_)))
+		)
+	};
+	assert!(partial("a:\n"));
+	assert!(partial("a:\n  b\n"));
+	assert!(partial("a:\n  b\n  "));
+	assert!(!partial("a:\n  b\nc"));
+	let lines = line().and_then(same_indent().and_then(line()).zero_or_more());
+	let err = lines.parse_complete("a\n  b\n").err().unwrap();
+	assert!(format!("{}", err)
+		.ends_with("\tat position 4 required indentation of 0 but failed to parse.\n"));
+}
@@ -0,0 +1,64 @@
+use crate::{
+	indentation::IndentationFound,
+	session,
+	Needed,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TabPolicy
+{
+	Reject,
+	Width(usize),
+}
+
+impl Default for TabPolicy
+{
+	fn default() -> Self
+	{
+		TabPolicy::Width(8)
+	}
+}
+
+pub(crate) fn measure(src: &str, pos: usize) -> Result<(usize, usize), (usize, IndentationFound)>
+{
+	if pos != 0 && !src[..pos].ends_with('\n')
+	{
+		return Err((pos, IndentationFound::NotLineStart));
+	}
+	let policy = session::tab_policy();
+	let bytes = src.as_bytes();
+	let mut line = pos;
+	loop
+	{
+		let mut at = line;
+		let mut width = 0;
+		loop
+		{
+			match (bytes.get(at), policy)
+			{
+				(Some(b' '), _) => width += 1,
+				(Some(b'\t'), TabPolicy::Width(tab)) => width = (width / tab + 1) * tab,
+				(Some(b'\t'), TabPolicy::Reject) => return Err((at, IndentationFound::Tab)),
+				_ => break,
+			}
+			at += 1;
+		}
+		match bytes.get(at)
+		{
+			Some(b'\n') => line = at + 1,
+			Some(b'\r') if bytes.get(at + 1) == Some(&b'\n') => line = at + 2,
+			Some(b'\r') if at + 1 == bytes.len() =>
+			{
+				session::reached_end(src, at + 1, Needed::Unknown);
+				return Ok((at, width));
+			}
+			None =>
+			{
+				// More input may continue the indentation or start another line.
+				session::reached_end(src, at, Needed::Unknown);
+				return Ok((at, 0));
+			}
+			Some(_) => return Ok((at, width)),
+		}
+	}
+}
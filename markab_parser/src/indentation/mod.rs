@@ -0,0 +1,27 @@
+mod dedent;
+mod error;
+mod indent;
+mod measure;
+mod requirement;
+mod same_indent;
+mod utility;
+
+pub use self::{
+	dedent::DedentParser,
+	error::{
+		IndentParserError,
+		IndentationError,
+		IndentationFound,
+	},
+	indent::IndentParser,
+	measure::TabPolicy,
+	requirement::IndentationRequirement,
+	same_indent::SameIndentParser,
+	utility::{
+		dedent,
+		indent,
+		same_indent,
+	},
+};
+
+pub(crate) use self::measure::measure;
@@ -0,0 +1,29 @@
+use std::fmt::{
+	Display,
+	Formatter,
+	Result as FmtResult,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndentationRequirement
+{
+	Indent(usize),
+	Same(usize),
+	Dedent(usize),
+}
+
+impl Display for IndentationRequirement
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		match self
+		{
+			IndentationRequirement::Indent(level) => write!(f, "indentation deeper than {}", level),
+			IndentationRequirement::Same(level) => write!(f, "indentation of {}", level),
+			IndentationRequirement::Dedent(level) =>
+			{
+				write!(f, "indentation shallower than {}", level)
+			}
+		}
+	}
+}
@@ -0,0 +1,92 @@
+use crate::{
+	indentation::{
+		measure,
+		IndentationError,
+		IndentationFound,
+		IndentationRequirement,
+	},
+	session,
+	Failure,
+	Nullable,
+	Parser,
+};
+use std::marker::PhantomData;
+
+#[derive(Debug)]
+pub struct SameIndentParser<'a>
+{
+	_a: PhantomData<&'a ()>,
+}
+
+impl<'a> SameIndentParser<'a>
+{
+	pub fn new() -> Self
+	{
+		Self { _a: PhantomData }
+	}
+
+	fn scan(&self, src: &'a str, from: usize) -> Result<usize, IndentationError>
+	{
+		let level = session::indent_level();
+		let (at, found) = match measure(src, from)
+		{
+			Ok((at, width)) if width == level && at != src.len() => return Ok(at),
+			Ok((at, width)) => (at, IndentationFound::Width(width)),
+			Err(failure) => failure,
+		};
+		session::record_failure(at, || IndentationRequirement::Same(level));
+		Err(IndentationError::new(
+			from,
+			IndentationRequirement::Same(level),
+			at,
+			found,
+		))
+	}
+}
+
+impl<'a> Default for SameIndentParser<'a>
+{
+	fn default() -> Self
+	{
+		Self::new()
+	}
+}
+
+impl<'a> Parser<'a> for SameIndentParser<'a>
+{
+	type Error = IndentationError;
+	type Output = ();
+	type Requirement = IndentationRequirement;
+	type RequirementContext = ();
+
+	fn parse(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Self::Error>
+	{
+		let src = session::metered(src, *pos);
+		let at = self.scan(src, *pos)?;
+		*pos = at;
+		Ok(())
+	}
+
+	fn attempt(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Failure<'a>>
+	{
+		let src = session::metered(src, *pos);
+		let at = self.scan(src, *pos).map_err(|_| Failure::new(*pos))?;
+		*pos = at;
+		Ok(())
+	}
+
+	fn nullable(&self) -> Nullable
+	{
+		Nullable::Yes
+	}
+
+	fn cheap_attempt(&self) -> bool
+	{
+		true
+	}
+
+	fn requirement(&self, _: Option<&Self::RequirementContext>) -> Self::Requirement
+	{
+		IndentationRequirement::Same(session::indent_level())
+	}
+}
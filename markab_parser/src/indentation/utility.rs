@@ -0,0 +1,25 @@
+use crate::{
+	indentation::{
+		DedentParser,
+		IndentParser,
+		SameIndentParser,
+	},
+	Parser,
+};
+
+pub fn indent<'a, P>(block: P) -> IndentParser<'a, P>
+where
+	P: Parser<'a>,
+{
+	IndentParser::new(block)
+}
+
+pub fn same_indent<'a>() -> SameIndentParser<'a>
+{
+	SameIndentParser::new()
+}
+
+pub fn dedent<'a>() -> DedentParser<'a>
+{
+	DedentParser::new()
+}
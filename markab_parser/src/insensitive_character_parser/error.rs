@@ -0,0 +1,78 @@
+use crate::{
+	insensitive_character_parser::InsensitiveCharacterParserRequirement,
+	Error,
+};
+use std::fmt::{
+	Display,
+	Formatter,
+	Result as FmtResult,
+};
+
+#[derive(Debug)]
+pub struct InsensitiveCharacterParserError
+{
+	from: usize,
+	requirement: InsensitiveCharacterParserRequirement,
+	found: Option<char>,
+}
+
+impl InsensitiveCharacterParserError
+{
+	pub fn new(
+		from: usize,
+		requirement: InsensitiveCharacterParserRequirement,
+		found: Option<char>,
+	) -> Self
+	{
+		Self {
+			from,
+			requirement,
+			found,
+		}
+	}
+}
+
+impl Error for InsensitiveCharacterParserError
+{
+	fn position(&self) -> usize
+	{
+		self.from
+	}
+
+	fn from(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "{}", self.from)
+	}
+
+	fn requirement(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "{}", self.requirement)
+	}
+
+	fn result(&self, f: &mut Formatter) -> FmtResult
+	{
+		match self.found
+		{
+			Some(found) => write!(f, "{:?} found", found),
+			None => write!(f, "not found"),
+		}
+	}
+
+	fn farthest(&self) -> usize
+	{
+		self.from
+	}
+
+	fn causes(&self, _: &mut Formatter, _: usize) -> FmtResult
+	{
+		Ok(())
+	}
+}
+
+impl Display for InsensitiveCharacterParserError
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		self.print(f, 0)
+	}
+}
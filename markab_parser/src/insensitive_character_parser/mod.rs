@@ -0,0 +1,11 @@
+mod error;
+mod parser;
+mod requirement;
+mod utility;
+
+pub use self::{
+	error::InsensitiveCharacterParserError,
+	parser::InsensitiveCharacterParser,
+	requirement::InsensitiveCharacterParserRequirement,
+	utility::insensitive_character,
+};
@@ -0,0 +1,88 @@
+use crate::{
+	insensitive_character_parser::{
+		InsensitiveCharacterParserError,
+		InsensitiveCharacterParserRequirement,
+	},
+	session,
+	CaseFolding,
+	Failure,
+	Needed,
+	Nullable,
+	Parser,
+};
+
+#[derive(Debug)]
+pub struct InsensitiveCharacterParser
+{
+	requirement: char,
+	folding: CaseFolding,
+}
+
+impl InsensitiveCharacterParser
+{
+	pub fn new(requirement: char, folding: CaseFolding) -> Self
+	{
+		Self {
+			requirement,
+			folding,
+		}
+	}
+
+	fn scan<'a, E, F>(&self, src: &'a str, pos: &mut usize, error: F) -> Result<&'a str, E>
+	where
+		F: FnOnce(usize, Option<char>) -> E,
+	{
+		let src = session::metered(src, *pos);
+		let from = *pos;
+		let next = src[from..].chars().next();
+		match next
+		{
+			Some(found) if self.folding.eq_char(self.requirement, found) =>
+			{
+				*pos += found.len_utf8();
+				Ok(&src[from..*pos])
+			}
+			_ =>
+			{
+				session::record_failure(from, || self.requirement(None));
+				session::reached_end(src, from, Needed::Size(1));
+				Err(error(from, next))
+			}
+		}
+	}
+}
+
+impl<'a> Parser<'a> for InsensitiveCharacterParser
+{
+	type Error = InsensitiveCharacterParserError;
+	type Output = &'a str;
+	type Requirement = InsensitiveCharacterParserRequirement;
+	type RequirementContext = ();
+
+	fn parse(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Self::Error>
+	{
+		self.scan(src, pos, |from, next| {
+			InsensitiveCharacterParserError::new(from, self.requirement(None), next)
+		})
+	}
+
+	fn attempt(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Failure<'a>>
+	{
+		self.scan(src, pos, |from, _| Failure::new(from))
+	}
+
+	fn nullable(&self) -> Nullable
+	{
+		Nullable::No
+	}
+
+	fn cheap_attempt(&self) -> bool
+	{
+		true
+	}
+
+	fn requirement(&self, _: Option<&Self::RequirementContext>) -> Self::Requirement
+	{
+		InsensitiveCharacterParserRequirement::new(self.requirement, self.folding)
+	}
+}
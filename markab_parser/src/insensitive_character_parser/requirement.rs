@@ -0,0 +1,42 @@
+use crate::CaseFolding;
+use std::fmt::{
+	Display,
+	Formatter,
+	Result as FmtResult,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub struct InsensitiveCharacterParserRequirement
+{
+	requirement: char,
+	folding: CaseFolding,
+}
+
+impl InsensitiveCharacterParserRequirement
+{
+	pub fn new(requirement: char, folding: CaseFolding) -> Self
+	{
+		Self {
+			requirement,
+			folding,
+		}
+	}
+
+	pub fn requirement(&self) -> char
+	{
+		self.requirement
+	}
+
+	pub fn folding(&self) -> CaseFolding
+	{
+		self.folding
+	}
+}
+
+impl Display for InsensitiveCharacterParserRequirement
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "{:?} ignoring {} case", self.requirement, self.folding)
+	}
+}
@@ -0,0 +1,10 @@
+use crate::{
+	insensitive_character_parser::InsensitiveCharacterParser,
+	CaseFolding,
+};
+
+pub fn insensitive_character(requirement: char, folding: CaseFolding)
+	-> InsensitiveCharacterParser
+{
+	InsensitiveCharacterParser::new(requirement, folding)
+}
@@ -0,0 +1,101 @@
+use crate::{
+	insensitive_string_parser::InsensitiveStringParserRequirement,
+	Error,
+};
+use std::fmt::{
+	Display,
+	Formatter,
+	Result as FmtResult,
+};
+
+#[derive(Debug)]
+pub struct InsensitiveStringParserError<'a>
+{
+	from: usize,
+	requirement: InsensitiveStringParserRequirement<'a>,
+	src: &'a str,
+}
+
+impl<'a> InsensitiveStringParserError<'a>
+{
+	pub fn new(
+		from: usize,
+		requirement: InsensitiveStringParserRequirement<'a>,
+		src: &'a str,
+	) -> Self
+	{
+		Self {
+			from,
+			requirement,
+			src,
+		}
+	}
+}
+
+impl<'a> Error for InsensitiveStringParserError<'a>
+{
+	fn position(&self) -> usize
+	{
+		self.from
+	}
+
+	fn from(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "{}", self.from)
+	}
+
+	fn requirement(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "{}", self.requirement)
+	}
+
+	fn result(&self, f: &mut Formatter) -> FmtResult
+	{
+		let found = &self.src[self.from..];
+		let len = found
+			.chars()
+			.take(self.requirement.requirement().chars().count())
+			.map(char::len_utf8)
+			.sum::<usize>();
+		if len == 0
+		{
+			write!(f, "not found")
+		}
+		else
+		{
+			write!(f, "{} found", &found[..len])
+		}
+	}
+
+	fn literal(&self) -> Option<&str>
+	{
+		Some(self.requirement.requirement())
+	}
+
+	fn farthest(&self) -> usize
+	{
+		let folding = self.requirement.folding();
+		self.from
+			+ self
+				.requirement
+				.requirement()
+				.chars()
+				.zip(self.src[self.from..].chars())
+				.take_while(|(required, found)| folding.eq_char(*required, *found))
+				.map(|(_, found)| found.len_utf8())
+				.sum::<usize>()
+	}
+
+	fn causes(&self, _: &mut Formatter, _: usize) -> FmtResult
+	{
+		Ok(())
+	}
+}
+
+impl<'a> Display for InsensitiveStringParserError<'a>
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		self.print(f, 0)
+	}
+}
@@ -0,0 +1,11 @@
+mod error;
+mod parser;
+mod requirement;
+mod utility;
+
+pub use self::{
+	error::InsensitiveStringParserError,
+	parser::InsensitiveStringParser,
+	requirement::InsensitiveStringParserRequirement,
+	utility::insensitive_string,
+};
@@ -0,0 +1,130 @@
+use crate::{
+	insensitive_string_parser::{
+		InsensitiveStringParserError,
+		InsensitiveStringParserRequirement,
+	},
+	session,
+	CaseFolding,
+	Failure,
+	Nullable,
+	Parser,
+};
+
+#[derive(Debug)]
+pub struct InsensitiveStringParser<'a>
+{
+	requirement: &'a str,
+	folding: CaseFolding,
+}
+
+impl<'a> InsensitiveStringParser<'a>
+{
+	pub fn new(requirement: &'a str, folding: CaseFolding) -> Self
+	{
+		Self {
+			requirement,
+			folding,
+		}
+	}
+
+	fn scan<E, F>(&self, src: &'a str, pos: &mut usize, error: F) -> Result<&'a str, E>
+	where
+		F: FnOnce(usize, &'a str) -> E,
+	{
+		let src = session::metered(src, *pos);
+		session::lexeme(src, pos, |pos| {
+			let from = *pos;
+			match self.folding.prefix_len(self.requirement, &src[from..])
+			{
+				Some(len) =>
+				{
+					*pos += len;
+					Ok(&src[from..*pos])
+				}
+				None =>
+				{
+					session::record_failure(from, || self.requirement(None));
+					session::reached_end_of_literal(src, from, self.requirement.chars(), |a, b| {
+						self.folding.eq_char(a, b)
+					});
+					Err(error(from, src))
+				}
+			}
+		})
+	}
+}
+
+impl<'a> Parser<'a> for InsensitiveStringParser<'a>
+{
+	type Error = InsensitiveStringParserError<'a>;
+	type Output = &'a str;
+	type Requirement = InsensitiveStringParserRequirement<'a>;
+	type RequirementContext = ();
+
+	fn parse(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Self::Error>
+	{
+		self.scan(src, pos, |from, src| {
+			InsensitiveStringParserError::new(from, self.requirement(None), src)
+		})
+	}
+
+	fn attempt(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Failure<'a>>
+	{
+		self.scan(src, pos, |from, _| Failure::new(from))
+	}
+
+	fn nullable(&self) -> Nullable
+	{
+		self.requirement.is_empty().into()
+	}
+
+	fn cheap_attempt(&self) -> bool
+	{
+		true
+	}
+
+	fn requirement(&self, _: Option<&Self::RequirementContext>) -> Self::Requirement
+	{
+		InsensitiveStringParserRequirement::new(self.requirement, self.folding)
+	}
+}
+
+#[test]
+fn test()
+{
+	use crate::insensitive_string;
+
+	let parser = insensitive_string("select", CaseFolding::Ascii);
+	let mut pos = 0;
+	assert_eq!(parser.parse("SeLeCt *", &mut pos).ok(), Some("SeLeCt"));
+	assert_eq!(pos, 6);
+	let mut pos = 0;
+	assert_eq!(
+		format!("{}", parser.parse("selekt", &mut pos).err().unwrap()),
+		"at position 0 required \"select\" ignoring ASCII case but selekt found.\n"
+	);
+
+	let parser = insensitive_string("éclair", CaseFolding::Unicode);
+	let mut pos = 0;
+	assert_eq!(parser.parse("ÉCLAIR", &mut pos).ok(), Some("ÉCLAIR"));
+	assert_eq!(
+		parser.requirement(None).to_string(),
+		"\"éclair\" ignoring Unicode case"
+	);
+	let mut pos = 0;
+	assert!(insensitive_string("éclair", CaseFolding::Ascii)
+		.parse("ÉCLAIR", &mut pos)
+		.is_err());
+	let parser = insensitive_string("straße", CaseFolding::Unicode);
+	let mut pos = 0;
+	assert_eq!(parser.parse("STRASSE", &mut pos).ok(), Some("STRASSE"));
+	let mut pos = 0;
+	assert_eq!(parser.parse("STRAẞE", &mut pos).ok(), Some("STRAẞE"));
+	let mut pos = 0;
+	assert_eq!(
+		insensitive_string("σοφός", CaseFolding::Unicode)
+			.parse("ΣΟΦΌΣ", &mut pos)
+			.ok(),
+		Some("ΣΟΦΌΣ")
+	);
+}
@@ -0,0 +1,42 @@
+use crate::CaseFolding;
+use std::fmt::{
+	Display,
+	Formatter,
+	Result as FmtResult,
+};
+
+#[derive(Debug)]
+pub struct InsensitiveStringParserRequirement<'a>
+{
+	requirement: &'a str,
+	folding: CaseFolding,
+}
+
+impl<'a> InsensitiveStringParserRequirement<'a>
+{
+	pub fn new(requirement: &'a str, folding: CaseFolding) -> Self
+	{
+		Self {
+			requirement,
+			folding,
+		}
+	}
+
+	pub fn requirement(&self) -> &'a str
+	{
+		self.requirement
+	}
+
+	pub fn folding(&self) -> CaseFolding
+	{
+		self.folding
+	}
+}
+
+impl<'a> Display for InsensitiveStringParserRequirement<'a>
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "{:?} ignoring {} case", self.requirement, self.folding)
+	}
+}
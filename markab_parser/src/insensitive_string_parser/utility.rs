@@ -0,0 +1,9 @@
+use crate::{
+	insensitive_string_parser::InsensitiveStringParser,
+	CaseFolding,
+};
+
+pub fn insensitive_string(requirement: &str, folding: CaseFolding) -> InsensitiveStringParser<'_>
+{
+	InsensitiveStringParser::new(requirement, folding)
+}
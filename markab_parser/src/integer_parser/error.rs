@@ -0,0 +1,105 @@
+use crate::{
+	integer_parser::IntegerParserRequirement,
+	Error,
+};
+use std::fmt::{
+	Display,
+	Formatter,
+	Result as FmtResult,
+};
+
+#[derive(Debug)]
+pub struct IntegerParserError<'a>
+{
+	from: usize,
+	requirement: IntegerParserRequirement,
+	src: &'a str,
+	at: usize,
+	overflow: bool,
+}
+
+impl<'a> IntegerParserError<'a>
+{
+	pub fn new(
+		from: usize,
+		requirement: IntegerParserRequirement,
+		src: &'a str,
+		at: usize,
+		overflow: bool,
+	) -> Self
+	{
+		Self {
+			from,
+			requirement,
+			src,
+			at,
+			overflow,
+		}
+	}
+
+	pub fn is_overflow(&self) -> bool
+	{
+		self.overflow
+	}
+}
+
+impl<'a> Error for IntegerParserError<'a>
+{
+	fn position(&self) -> usize
+	{
+		self.from
+	}
+
+	fn from(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "{}", self.from)
+	}
+
+	fn requirement(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "{}", self.requirement)
+	}
+
+	fn result(&self, f: &mut Formatter) -> FmtResult
+	{
+		if self.overflow
+		{
+			return write!(
+				f,
+				"{} found, which overflows {}",
+				&self.src[self.from..self.at],
+				self.requirement
+			);
+		}
+		match self.src[self.at..].chars().next()
+		{
+			Some(found) => write!(f, "{:?} found", found),
+			None => write!(f, "not found"),
+		}
+	}
+
+	fn farthest(&self) -> usize
+	{
+		if self.overflow
+		{
+			self.from
+		}
+		else
+		{
+			self.at
+		}
+	}
+
+	fn causes(&self, _: &mut Formatter, _: usize) -> FmtResult
+	{
+		Ok(())
+	}
+}
+
+impl<'a> Display for IntegerParserError<'a>
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		self.print(f, 0)
+	}
+}
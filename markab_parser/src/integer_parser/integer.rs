@@ -0,0 +1,40 @@
+use std::fmt::Debug;
+
+pub trait Integer: Copy + Debug
+{
+	const SIGNED: bool;
+
+	fn zero() -> Self;
+	fn accumulate(self, radix: u32, digit: u32, negative: bool) -> Option<Self>;
+}
+
+macro_rules! integer {
+	($($t:ty),*) => {
+		$(
+			impl Integer for $t
+			{
+				const SIGNED: bool = <$t>::MIN != 0;
+
+				fn zero() -> Self
+				{
+					0
+				}
+
+				fn accumulate(self, radix: u32, digit: u32, negative: bool) -> Option<Self>
+				{
+					let shifted = self.checked_mul(radix as $t)?;
+					if negative
+					{
+						shifted.checked_sub(digit as $t)
+					}
+					else
+					{
+						shifted.checked_add(digit as $t)
+					}
+				}
+			}
+		)*
+	};
+}
+
+integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
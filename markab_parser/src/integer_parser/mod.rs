@@ -0,0 +1,13 @@
+mod error;
+mod integer;
+mod parser;
+mod requirement;
+mod utility;
+
+pub use self::{
+	error::IntegerParserError,
+	integer::Integer,
+	parser::IntegerParser,
+	requirement::IntegerParserRequirement,
+	utility::integer,
+};
@@ -0,0 +1,264 @@
+use crate::{
+	integer_parser::{
+		Integer,
+		IntegerParserError,
+		IntegerParserRequirement,
+	},
+	session,
+	Failure,
+	Needed,
+	Nullable,
+	Parser,
+};
+use std::{
+	any::type_name,
+	marker::PhantomData,
+};
+
+#[derive(Debug)]
+pub struct IntegerParser<'a, T>
+where
+	T: Integer,
+{
+	radix: u32,
+	sign: bool,
+	radix_prefixes: bool,
+	separators: bool,
+	_a: PhantomData<&'a T>,
+}
+
+impl<'a, T> IntegerParser<'a, T>
+where
+	T: Integer,
+{
+	pub fn new() -> Self
+	{
+		Self {
+			radix: 10,
+			sign: true,
+			radix_prefixes: true,
+			separators: true,
+			_a: PhantomData,
+		}
+	}
+
+	pub fn radix(mut self, radix: u32) -> Self
+	{
+		assert!(
+			(2..=36).contains(&radix),
+			"radix {} is not in 2..=36",
+			radix
+		);
+		self.radix = radix;
+		self
+	}
+
+	pub fn sign(mut self, sign: bool) -> Self
+	{
+		self.sign = sign;
+		self
+	}
+
+	pub fn radix_prefixes(mut self, radix_prefixes: bool) -> Self
+	{
+		self.radix_prefixes = radix_prefixes;
+		self
+	}
+
+	pub fn separators(mut self, separators: bool) -> Self
+	{
+		self.separators = separators;
+		self
+	}
+
+	fn scan(&self, src: &str, from: usize) -> Result<(T, usize), (usize, bool)>
+	{
+		let bytes = src.as_bytes();
+		let mut at = from;
+		let mut negative = false;
+		if self.sign
+		{
+			match bytes.get(at)
+			{
+				Some(b'+') => at += 1,
+				Some(b'-') if T::SIGNED =>
+				{
+					negative = true;
+					at += 1;
+				}
+				_ => (),
+			}
+		}
+		let mut radix = self.radix;
+		let mut prefixed = false;
+		if self.radix_prefixes && radix == 10 && bytes.get(at) == Some(&b'0')
+		{
+			radix = match bytes.get(at + 1)
+			{
+				Some(b'x') => 16,
+				Some(b'o') => 8,
+				Some(b'b') => 2,
+				_ => radix,
+			};
+			if radix != 10
+			{
+				prefixed = true;
+				at += 2;
+			}
+		}
+		let mut value = T::zero();
+		let mut digits = 0;
+		let mut overflow = false;
+		while let Some(&byte) = bytes.get(at)
+		{
+			if byte == b'_' && self.separators && (digits != 0 || prefixed)
+			{
+				at += 1;
+				continue;
+			}
+			let digit = match (byte as char).to_digit(radix)
+			{
+				Some(digit) => digit,
+				None => break,
+			};
+			match value.accumulate(radix, digit, negative)
+			{
+				Some(next) => value = next,
+				None => overflow = true,
+			}
+			digits += 1;
+			at += 1;
+		}
+		session::reached_end(src, at, Needed::Unknown);
+		if digits == 0
+		{
+			Err((at, false))
+		}
+		else if overflow
+		{
+			Err((at, true))
+		}
+		else
+		{
+			Ok((value, at))
+		}
+	}
+}
+
+impl<'a, T> Default for IntegerParser<'a, T>
+where
+	T: Integer,
+{
+	fn default() -> Self
+	{
+		Self::new()
+	}
+}
+
+impl<'a, T> Parser<'a> for IntegerParser<'a, T>
+where
+	T: Integer,
+{
+	type Error = IntegerParserError<'a>;
+	type Output = T;
+	type Requirement = IntegerParserRequirement;
+	type RequirementContext = ();
+
+	fn parse(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Self::Error>
+	{
+		let src = session::metered(src, *pos);
+		session::lexeme(src, pos, |pos| {
+			let from = *pos;
+			match self.scan(src, from)
+			{
+				Ok((value, end)) =>
+				{
+					*pos = end;
+					Ok(value)
+				}
+				Err((at, overflow)) =>
+				{
+					session::record_failure(from, || self.requirement(None));
+					Err(IntegerParserError::new(
+						from,
+						self.requirement(None),
+						src,
+						at,
+						overflow,
+					))
+				}
+			}
+		})
+	}
+
+	fn attempt(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Failure<'a>>
+	{
+		let src = session::metered(src, *pos);
+		session::lexeme(src, pos, |pos| {
+			let from = *pos;
+			match self.scan(src, from)
+			{
+				Ok((value, end)) =>
+				{
+					*pos = end;
+					Ok(value)
+				}
+				Err(_) =>
+				{
+					session::record_failure(from, || self.requirement(None));
+					Err(Failure::new(from))
+				}
+			}
+		})
+	}
+
+	fn nullable(&self) -> Nullable
+	{
+		Nullable::No
+	}
+
+	fn cheap_attempt(&self) -> bool
+	{
+		true
+	}
+
+	fn requirement(&self, _: Option<&Self::RequirementContext>) -> Self::Requirement
+	{
+		IntegerParserRequirement::new(type_name::<T>(), self.radix)
+	}
+}
+
+#[test]
+fn test()
+{
+	use crate::integer;
+
+	let mut pos = 0;
+	assert_eq!(
+		integer::<i32>().parse("-1_000;", &mut pos).ok(),
+		Some(-1000)
+	);
+	assert_eq!(pos, 6);
+	let mut pos = 0;
+	assert_eq!(
+		integer::<u16>().parse("0xff_ff", &mut pos).ok(),
+		Some(0xffff)
+	);
+	let mut pos = 0;
+	assert_eq!(integer::<i8>().parse("-128", &mut pos).ok(), Some(-128));
+	let mut pos = 0;
+	assert_eq!(
+		format!("{}", integer::<u8>().parse("256", &mut pos).err().unwrap()),
+		"at position 0 required u8 but 256 found, which overflows u8.\n"
+	);
+	let mut pos = 0;
+	assert_eq!(
+		format!("{}", integer::<u8>().parse("-1", &mut pos).err().unwrap()),
+		"at position 0 required u8 but '-' found.\n"
+	);
+	let mut pos = 0;
+	assert_eq!(
+		integer::<u32>().radix(16).parse("0b1", &mut pos).ok(),
+		Some(0x0b1)
+	);
+}
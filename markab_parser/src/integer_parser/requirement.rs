@@ -0,0 +1,35 @@
+use std::fmt::{
+	Display,
+	Formatter,
+	Result as FmtResult,
+};
+
+#[derive(Debug)]
+pub struct IntegerParserRequirement
+{
+	name: &'static str,
+	radix: u32,
+}
+
+impl IntegerParserRequirement
+{
+	pub fn new(name: &'static str, radix: u32) -> Self
+	{
+		Self { name, radix }
+	}
+}
+
+impl Display for IntegerParserRequirement
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		if self.radix == 10
+		{
+			write!(f, "{}", self.name)
+		}
+		else
+		{
+			write!(f, "{} in radix {}", self.name, self.radix)
+		}
+	}
+}
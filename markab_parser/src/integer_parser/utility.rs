@@ -0,0 +1,11 @@
+use crate::integer_parser::{
+	Integer,
+	IntegerParser,
+};
+
+pub fn integer<'a, T>() -> IntegerParser<'a, T>
+where
+	T: Integer,
+{
+	IntegerParser::new()
+}
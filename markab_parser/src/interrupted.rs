@@ -0,0 +1,97 @@
+use crate::{
+	BudgetExhausted,
+	Error,
+	Incomplete,
+	NestingTooDeep,
+};
+use std::fmt::{
+	Display,
+	Formatter,
+	Result as FmtResult,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interrupted
+{
+	NestingTooDeep(NestingTooDeep),
+	BudgetExhausted(BudgetExhausted),
+	Incomplete(Incomplete),
+}
+
+impl Interrupted
+{
+	fn error(&self) -> &dyn Error
+	{
+		match self
+		{
+			Self::NestingTooDeep(err) => err,
+			Self::BudgetExhausted(err) => err,
+			Self::Incomplete(err) => err,
+		}
+	}
+}
+
+impl From<NestingTooDeep> for Interrupted
+{
+	fn from(err: NestingTooDeep) -> Self
+	{
+		Self::NestingTooDeep(err)
+	}
+}
+
+impl From<BudgetExhausted> for Interrupted
+{
+	fn from(err: BudgetExhausted) -> Self
+	{
+		Self::BudgetExhausted(err)
+	}
+}
+
+impl From<Incomplete> for Interrupted
+{
+	fn from(err: Incomplete) -> Self
+	{
+		Self::Incomplete(err)
+	}
+}
+
+impl Error for Interrupted
+{
+	fn position(&self) -> usize
+	{
+		self.error().position()
+	}
+
+	fn from(&self, f: &mut Formatter) -> FmtResult
+	{
+		self.error().from(f)
+	}
+
+	fn requirement(&self, f: &mut Formatter) -> FmtResult
+	{
+		self.error().requirement(f)
+	}
+
+	fn result(&self, f: &mut Formatter) -> FmtResult
+	{
+		self.error().result(f)
+	}
+
+	fn farthest(&self) -> usize
+	{
+		self.error().farthest()
+	}
+
+	fn causes(&self, f: &mut Formatter, depth: usize) -> FmtResult
+	{
+		self.error().causes(f, depth)
+	}
+}
+
+impl Display for Interrupted
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		self.print(f, 0)
+	}
+}
@@ -0,0 +1,217 @@
+use crate::{
+	session::Snapshot,
+	Error,
+	Failure,
+	Parser,
+};
+use std::{
+	cell::OnceCell,
+	fmt::{
+		Debug,
+		Display,
+		Formatter,
+		Result as FmtResult,
+	},
+};
+
+pub struct LazyError<'a, 'p, P>
+where
+	P: Parser<'a>,
+{
+	parser: &'p P,
+	src: &'a str,
+	failure: Failure<'a>,
+	snapshot: Box<Snapshot>,
+	error: OnceCell<Option<P::Error>>,
+}
+
+impl<'a, 'p, P> LazyError<'a, 'p, P>
+where
+	P: Parser<'a>,
+{
+	pub fn new(parser: &'p P, src: &'a str, failure: Failure<'a>) -> Self
+	{
+		let error = Self {
+			parser,
+			src,
+			failure,
+			snapshot: Box::new(Snapshot::take()),
+			error: OnceCell::new(),
+		};
+		// The user state cannot be copied, so the error is built while it is still current.
+		if Snapshot::has_state()
+		{
+			error.materialize();
+		}
+		error
+	}
+
+	pub fn failure(&self) -> Failure<'a>
+	{
+		self.failure
+	}
+
+	pub fn materialize(&self) -> Option<&P::Error>
+	{
+		self.error
+			.get_or_init(|| {
+				self.snapshot.replay(|| {
+					let mut pos = self.failure.from();
+					self.parser.parse(self.src, &mut pos).err()
+				})
+			})
+			.as_ref()
+	}
+}
+
+impl<'a, 'p, P> Debug for LazyError<'a, 'p, P>
+where
+	P: Parser<'a>,
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		f.debug_struct("LazyError")
+			.field("parser", &self.parser)
+			.field("failure", &self.failure)
+			.finish()
+	}
+}
+
+impl<'a, 'p, P> Error for LazyError<'a, 'p, P>
+where
+	P: Parser<'a>,
+{
+	fn position(&self) -> usize
+	{
+		self.failure.from()
+	}
+
+	fn from(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "{}", self.failure.from())
+	}
+
+	fn requirement(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "{}", self.parser.requirement(None))
+	}
+
+	fn result(&self, f: &mut Formatter) -> FmtResult
+	{
+		match (self.materialize(), self.failure.rule())
+		{
+			(Some(err), _) => err.result(f),
+			(None, Some(rule)) => write!(f, "failed to parse {}", rule),
+			(None, None) => write!(f, "failed to parse"),
+		}
+	}
+
+	fn farthest(&self) -> usize
+	{
+		self.materialize()
+			.map_or(self.failure.from(), |err| err.farthest())
+	}
+
+	fn causes(&self, f: &mut Formatter, depth: usize) -> FmtResult
+	{
+		match self.materialize()
+		{
+			Some(err) => err.causes(f, depth),
+			None => Ok(()),
+		}
+	}
+
+	fn for_each_cause<'s>(&'s self, f: &mut dyn FnMut(&'s dyn Error))
+	{
+		if let Some(err) = self.materialize()
+		{
+			err.for_each_cause(f)
+		}
+	}
+
+	fn delegate(&self) -> Option<&dyn Error>
+	{
+		self.materialize().map(|err| err as &dyn Error)
+	}
+}
+
+impl<'a, 'p, P> Display for LazyError<'a, 'p, P>
+where
+	P: Parser<'a>,
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		self.print(f, 0)
+	}
+}
+
+#[test]
+fn test()
+{
+	use crate::{
+		modify_state,
+		string,
+		string_parser::StringParserError,
+		Parseable,
+		Session,
+		TriviaPosition,
+	};
+
+	struct Keyword;
+
+	impl<'a> Parseable<'a> for Keyword
+	{
+		type Error = StringParserError<'a>;
+		type Output = &'a str;
+
+		fn parse(src: &'a str, pos: &mut usize) -> Result<Self::Output, Self::Error>
+		{
+			string("x").parse(src, pos)
+		}
+
+		fn attempt(_: &'a str, pos: &mut usize) -> Result<Self::Output, Failure<'a>>
+		{
+			Err(Failure::named(*pos, Self::name()))
+		}
+
+		fn name() -> &'a str
+		{
+			"keyword"
+		}
+	}
+
+	let parser = Keyword::get_parser();
+	let err = parser.parse_lazy("x", &mut 0).err().unwrap();
+	assert!(err.materialize().is_none());
+	assert_eq!(err.farthest(), 0);
+	assert_eq!(
+		format!("{}", err),
+		"at position 0 required keyword but failed to parse keyword.\n"
+	);
+
+	fn space(src: &str, pos: &mut usize)
+	{
+		*pos += src[*pos..].len() - src[*pos..].trim_start().len();
+	}
+
+	let parser = string("x").and_then(string("y"));
+	let err = Session::new()
+		.trivia(space, TriviaPosition::After)
+		.run(|| parser.parse_lazy("x z", &mut 0).err())
+		.unwrap();
+	assert_eq!(err.farthest(), 2);
+	assert!(format!("{}", err).ends_with("\tat position 2 required y but z found.\n"));
+
+	let parser = string("x")
+		.map(&|_| {
+			modify_state(|count: &mut usize| {
+				*count += 1;
+				|count: &mut usize| *count -= 1
+			});
+		})
+		.and_then(string("y"));
+	let (err, count) =
+		Session::new().run_with_state(0usize, || parser.parse_lazy("xz", &mut 0).err());
+	assert_eq!(count, 0);
+	assert_eq!(err.unwrap().farthest(), 1);
+}
@@ -0,0 +1,120 @@
+use crate::{
+	session::{
+		self,
+		TriviaGuard,
+	},
+	Failure,
+	Nullable,
+	Parser,
+};
+use std::marker::PhantomData;
+
+#[derive(Debug)]
+pub struct LexemeParser<'a, P>
+where
+	P: Parser<'a>,
+{
+	requirement: P,
+	_a: PhantomData<&'a ()>,
+}
+
+impl<'a, P> LexemeParser<'a, P>
+where
+	P: Parser<'a>,
+{
+	pub fn new(requirement: P) -> Self
+	{
+		Self {
+			requirement,
+			_a: PhantomData,
+		}
+	}
+}
+
+impl<'a, P> Parser<'a> for LexemeParser<'a, P>
+where
+	P: Parser<'a>,
+{
+	type Error = P::Error;
+	type Output = P::Output;
+	type Requirement = P::Requirement;
+	type RequirementContext = P::RequirementContext;
+
+	fn parse(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Self::Error>
+	{
+		session::lexeme(src, pos, |pos| {
+			let _guard = TriviaGuard::disable();
+			self.requirement.parse(src, pos)
+		})
+	}
+
+	fn skip(&self, src: &'a str, pos: &mut usize) -> Result<(), Self::Error>
+	{
+		session::lexeme(src, pos, |pos| {
+			let _guard = TriviaGuard::disable();
+			self.requirement.skip(src, pos)
+		})
+	}
+
+	fn attempt(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Failure<'a>>
+	{
+		session::lexeme(src, pos, |pos| {
+			let _guard = TriviaGuard::disable();
+			self.requirement.attempt(src, pos)
+		})
+	}
+
+	fn nullable(&self) -> Nullable
+	{
+		self.requirement.nullable()
+	}
+
+	fn cheap_attempt(&self) -> bool
+	{
+		self.requirement.cheap_attempt()
+	}
+
+	fn requirement(&self, context: Option<&Self::RequirementContext>) -> Self::Requirement
+	{
+		self.requirement.requirement(context)
+	}
+}
+
+#[test]
+fn test()
+{
+	use crate::{
+		string,
+		take_while,
+		Session,
+		TriviaPosition,
+	};
+
+	fn whitespace(src: &str, pos: &mut usize)
+	{
+		let _ = take_while("whitespace", &char::is_whitespace, 0, usize::MAX).skip(src, pos);
+	}
+
+	let identifier = take_while("alphabetic", &char::is_alphabetic, 1, usize::MAX).lexeme();
+	let parser = string("let")
+		.and_then(identifier)
+		.and_then(string("="))
+		.and_then(string("x y").no_trivia());
+	let session = Session::new()
+		.trivia(whitespace, TriviaPosition::After)
+		.retain_trivia(true);
+	let mut pos = 0;
+	let (result, trivia) = session.parse_with_trivia(&parser, "let a  = x y ;", &mut pos);
+	assert_eq!(result.ok().map(|(((_, a), _), _)| a), Some("a"));
+	assert_eq!(pos, 12);
+	assert_eq!(trivia, vec![3..4, 5..7, 8..9]);
+	let mut pos = 0;
+	assert!(parser.parse("let a = x y", &mut pos).is_err());
+	let parser = string("a")
+		.and_then(string("x"))
+		.or(string("a  b").no_trivia());
+	let mut pos = 0;
+	let (result, trivia) = session.parse_with_trivia(&parser, "a  b", &mut pos);
+	assert!(result.is_ok());
+	assert_eq!(trivia, vec![]);
+}
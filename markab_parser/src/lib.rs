@@ -30,6 +30,141 @@
 //! assert_eq!(pos, 11);
 //! ```
 //!
+//! [satisfy] and [take_while] match characters by a predicate, [take_until] scans until a terminator,
+//! and [any_char] and [end_of_input] match any character and the end of the source.
+//!
+//! [satisfy]: fn.satisfy.html
+//! [take_while]: fn.take_while.html
+//! [take_until]: fn.take_until.html
+//! [any_char]: fn.any_char.html
+//! [end_of_input]: fn.end_of_input.html
+//!
+//! ```
+//! use markab_parser::{
+//! 	satisfy,
+//! 	take_while,
+//! 	Parser,
+//! 	};
+//!
+//! let identifier = satisfy("alphabetic", &char::is_alphabetic)
+//! 	.and_then(take_while("alphanumeric", &char::is_alphanumeric, 0, usize::MAX))
+//! 	.stringify();
+//! let mut pos = 0;
+//! assert_eq!(identifier.parse("x86 = 1", &mut pos).ok(), Some("x86"));
+//! ```
+//!
+//! [character_set] matches a [CharSet], which is built with union, intersection, difference and complement
+//! from characters, ranges and Unicode properties.
+//!
+//! [character_set]: fn.character_set.html
+//! [CharSet]: character_set/struct.CharSet.html
+//!
+//! ```
+//! use markab_parser::{
+//! 	character_set,
+//! 	CharSet,
+//! 	Parser,
+//! 	};
+//!
+//! let set = CharSet::xid_start().union(CharSet::new(&['$'], &[]));
+//! let parser = character_set(set);
+//! let mut pos = 0;
+//! assert_eq!(parser.parse("$x", &mut pos).ok(), Some("$"));
+//! let mut pos = 0;
+//! assert_eq!(
+//! 	format!("{}", parser.parse("1", &mut pos).err().unwrap()),
+//! 	"at position 0 required [\\p{XID_Start}[$]] but '1' found.\n"
+//! );
+//! ```
+//!
+//! [insensitive_string] and [insensitive_character] ignore the case with ASCII or Unicode [CaseFolding],
+//! and return the slice of the source in its original case.
+//!
+//! [insensitive_string]: fn.insensitive_string.html
+//! [insensitive_character]: fn.insensitive_character.html
+//! [CaseFolding]: enum.CaseFolding.html
+//!
+//! ```
+//! use markab_parser::{
+//! 	insensitive_string,
+//! 	CaseFolding,
+//! 	Parser,
+//! 	};
+//!
+//! let parser = insensitive_string("select", CaseFolding::Ascii);
+//! let mut pos = 0;
+//! assert_eq!(parser.parse("SELECT *", &mut pos).ok(), Some("SELECT"));
+//! ```
+//!
+//! [literal_set] and [literal_map] match the longest of many literals at once,
+//! and return it with its index or its associated value.
+//!
+//! [literal_set]: fn.literal_set.html
+//! [literal_map]: fn.literal_map.html
+//!
+//! ```
+//! use markab_parser::{
+//! 	literal_map,
+//! 	Parser,
+//! 	};
+//!
+//! #[derive(Debug, Clone, PartialEq)]
+//! enum Operator
+//! {
+//! 	Less,
+//! 	LessEqual,
+//! }
+//!
+//! let parser = literal_map(vec![("<", Operator::Less), ("<=", Operator::LessEqual)]);
+//! let mut pos = 0;
+//! assert_eq!(parser.parse("<= 1", &mut pos).ok(), Some(("<=", Operator::LessEqual)));
+//! ```
+//!
+//! [integer] parses any primitive integer type with sign, `0x`, `0o` and `0b` prefixes and `_` separators,
+//! and reports overflow as an error. [float] parses floating point numbers in Rust or JSON [FloatSyntax].
+//! Separators are stripped into a stack buffer, so only numbers longer than 64 bytes with separators allocate.
+//!
+//! [integer]: fn.integer.html
+//! [float]: fn.float.html
+//! [FloatSyntax]: float_parser/enum.FloatSyntax.html
+//!
+//! ```
+//! use markab_parser::{
+//! 	float,
+//! 	integer,
+//! 	FloatSyntax,
+//! 	Parser,
+//! 	};
+//!
+//! let mut pos = 0;
+//! assert_eq!(integer::<u32>().parse("0x1_F", &mut pos).ok(), Some(31));
+//! let mut pos = 0;
+//! assert!(integer::<u8>().parse("300", &mut pos).is_err());
+//! let mut pos = 0;
+//! assert_eq!(float::<f64>(FloatSyntax::Json).parse("-1.5e3", &mut pos).ok(), Some(-1500.0));
+//! ```
+//!
+//! [string_literal] parses a quoted string with Rust, JSON or C [EscapeDialect],
+//! and borrows its content from the source unless it contains escapes.
+//!
+//! [string_literal]: fn.string_literal.html
+//! [EscapeDialect]: string_literal_parser/enum.EscapeDialect.html
+//!
+//! ```
+//! use markab_parser::{
+//! 	string_literal,
+//! 	EscapeDialect,
+//! 	Parser,
+//! 	};
+//! use std::borrow::Cow;
+//!
+//! let parser = string_literal(EscapeDialect::Json);
+//! let mut pos = 0;
+//! assert_eq!(parser.parse(r#""a\nb""#, &mut pos).ok(), Some(Cow::from("a\nb")));
+//! let mut pos = 0;
+//! assert!(matches!(parser.parse(r#""ab""#, &mut pos), Ok(Cow::Borrowed("ab"))));
+//! ```
+//!
 //! # Combinate parser
 //! [Parser] trait has methods for parser combination.
 //!
@@ -93,31 +228,579 @@
 //! 		}
 //! 	}
 //! ```
+//!
+//! # Cheap failures
+//! Building a rich error means building the requirement tree of every failed parser,
+//! which is wasted work when the error is thrown away by backtracking.
+//! [Parser::attempt] reports a failure as a [Failure], which only holds the position
+//! and the name of the failed rule.
+//! [Parser::parse_lazy] returns a [LazyError] that builds the rich error
+//! by replaying the parser only when it is inspected or printed.
+//! The replay runs under the settings, indentation and captures of the original [Session],
+//! even after it has returned.
+//! Errors of a session with user state are built at once, since the state cannot be replayed.
+//! When the replay does not fail, the error is described by its [Failure] alone.
+//!
+//! `or` tries its branches with [Parser::attempt] and replays them to build the error
+//! only when [Parser::cheap_attempt] says that `attempt` does not call `parse`.
+//! Rules implementing [Parseable::attempt] should override [Parseable::cheap_attempt].
+//!
+//! [Parser::attempt]: trait.Parser.html#method.attempt
+//! [Parser::cheap_attempt]: trait.Parser.html#method.cheap_attempt
+//! [Parseable::attempt]: trait.Parseable.html#method.attempt
+//! [Parseable::cheap_attempt]: trait.Parseable.html#method.cheap_attempt
+//! [Parser::parse_lazy]: trait.Parser.html#method.parse_lazy
+//! [Failure]: struct.Failure.html
+//! [LazyError]: struct.LazyError.html
+//!
+//! ```
+//! use markab_parser::{
+//! 	string,
+//! 	Parser,
+//! 	};
+//!
+//! let parser = string("include").or(string("import"));
+//! let mut pos = 0;
+//! let err = parser.parse_lazy("export", &mut pos).err().unwrap();
+//! assert_eq!(err.failure().from(), 0);
+//! assert_eq!(
+//! 	format!("{}", err),
+//! 	"at position 0 required include / import but failed to parse.\n\
+//! 	\tat position 0 required include but export found.\n\
+//! 	\tat position 0 required import but export found.\n"
+//! );
+//! ```
+//!
+//! # Complete input
+//! [Parser::parse] succeeds when a prefix of the source matches.
+//! [Parser::parse_complete] and [Parseable::parse_str] parse the whole source from offset 0,
+//! and report unconsumed input together with the farthest failure of the grammar.
+//!
+//! [Parser::parse]: trait.Parser.html#tymethod.parse
+//! [Parser::parse_complete]: trait.Parser.html#method.parse_complete
+//! [Parseable::parse_str]: trait.Parseable.html#method.parse_str
+//!
+//! ```
+//! use markab_parser::{
+//! 	character,
+//! 	character_class,
+//! 	Parser,
+//! 	};
+//!
+//! let number = || character_class(false, &['0', '1', '2', '3'], &[]).one_or_more();
+//! let sum = number().and_then(character('+').and_then(number()).zero_or_more());
+//! assert!(sum.parse_complete("1+23").is_ok());
+//! let err = sum.parse_complete("1+23+").err().unwrap();
+//! assert_eq!(
+//! 	format!("{}", err),
+//! 	"at position 4 required end of input but \"+\" found.\n\
+//! 	\tat position 5 required [0123] but failed to parse.\n"
+//! );
+//! ```
+//!
+//! # Sessions
+//! A [Session] holds the settings of a parse, and applies them while running a parser.
+//! [ErrorLimits] bounds the size of error trees while they are constructed:
+//! the maximum depth of nested [Parseable] rules, the maximum number of alternatives
+//! kept by ordered choices, and whether only the alternative which reached farthest is kept.
+//! Pruned causes are marked in the printed error.
+//!
+//! [Session]: struct.Session.html
+//! [ErrorLimits]: struct.ErrorLimits.html
+//!
+//! ```
+//! use markab_parser::{
+//! 	string,
+//! 	ErrorLimits,
+//! 	Parser,
+//! 	Session,
+//! 	};
+//!
+//! let parser = string("include").or(string("import"));
+//! let session = Session::new().error_limits(ErrorLimits::new().farthest_only(true));
+//! let mut pos = 0;
+//! let err = session.parse(&parser, "impart", &mut pos).err().unwrap();
+//! assert_eq!(
+//! 	format!("{}", err),
+//! 	"at position 0 required include / import but failed to parse.\n\
+//! 	\tat position 0 required import but impart found.\n\
+//! 	\t(some causes were pruned)\n"
+//! );
+//! ```
+//!
+//! [Session::max_nesting] bounds the depth of nested [Parseable] rules,
+//! so deeply nested input fails with a "nesting too deep" error instead of overflowing the stack.
+//! The methods of [Session] which parse return [NestingTooDeep] as an [Interrupted] error
+//! even when a rule replaces the error of the rule it called.
+//! With the `stacker` feature, [Session::grow_stack] grows the stack on demand for trusted input.
+//!
+//! [Session::max_nesting]: struct.Session.html#method.max_nesting
+//! [NestingTooDeep]: struct.NestingTooDeep.html
+//! [Interrupted]: enum.Interrupted.html
+//! [Session::grow_stack]: struct.Session.html#method.grow_stack
+//!
+//! [Session::parse_with_budget] bounds the work of a parse by the number of primitive parser invocations.
+//! When the budget runs out, remaining primitives see the end of input
+//! and the parse returns [BudgetExhausted] with the offset where it stopped.
+//!
+//! [Session::parse_with_budget]: struct.Session.html#method.parse_with_budget
+//! [BudgetExhausted]: struct.BudgetExhausted.html
+//!
+//! ```
+//! use markab_parser::{
+//! 	string,
+//! 	Parser,
+//! 	Session,
+//! 	};
+//!
+//! let parser = string("a").or(string("b")).zero_or_more();
+//! let src = "ab".repeat(1000);
+//! let mut pos = 0;
+//! let err = Session::new()
+//! 	.parse_with_budget(&parser, &src, &mut pos, 100)
+//! 	.err()
+//! 	.unwrap();
+//! assert!(format!("{}", err).ends_with("but budget exhausted at offset 67.\n"));
+//! ```
+//!
+//! # Partial input
+//! [Session::parse_partial] parses input which may continue, such as a network stream.
+//! When a primitive parser reaches the end of the available input, the parse returns
+//! [Incomplete] with the [Needed] amount of input, so that the caller can append more data and parse again.
+//! Once the stream is closed, parse the rest with [Session::parse].
+//!
+//! [Session::parse_partial]: struct.Session.html#method.parse_partial
+//! [Session::parse]: struct.Session.html#method.parse
+//! [Incomplete]: struct.Incomplete.html
+//! [Needed]: enum.Needed.html
+//!
+//! ```
+//! use markab_parser::{
+//! 	string,
+//! 	Incomplete,
+//! 	Interrupted,
+//! 	Needed,
+//! 	Parser,
+//! 	Session,
+//! 	};
+//!
+//! let parser = string("HTTP/1.1 ").and_then(string("200"));
+//! let session = Session::new();
+//! let mut buffer = String::from("HTTP/1.1 2");
+//! let mut pos = 0;
+//! let incomplete = session
+//! 	.parse_partial(&parser, &buffer, &mut pos)
+//! 	.err()
+//! 	.and_then(|err| err.right());
+//! assert_eq!(
+//! 	incomplete,
+//! 	Some(Interrupted::Incomplete(Incomplete::new(10, Needed::Size(2))))
+//! );
+//! buffer.push_str("00 OK");
+//! assert!(session.parse_partial(&parser, &buffer, &mut pos).is_ok());
+//! assert_eq!(pos, 12);
+//! ```
+//!
+//! [RecordReader] parses a sequence of records from a [BufRead] source without loading it at once.
+//! It reads until a record of the [Parseable] type is complete, and discards the record
+//! when the next one is requested, so each output borrows the buffer only until then.
+//! A record parser which succeeds without consuming input would read the same record forever,
+//! so it is reported as an I/O error of kind `InvalidInput`.
+//! A record longer than [RecordReader::max_record_size], 1 MiB by default, is reported as `InvalidData`.
+//! After a failed record, [RecordReader::skip_past] discards the input up to a delimiter to resume.
+//!
+//! [RecordReader]: struct.RecordReader.html
+//! [RecordReader::max_record_size]: struct.RecordReader.html#method.max_record_size
+//! [RecordReader::skip_past]: struct.RecordReader.html#method.skip_past
+//! [BufRead]: https://doc.rust-lang.org/std/io/trait.BufRead.html
+//!
+//! ```
+//! use markab_parser::{
+//! 	string,
+//! 	string_parser::StringParser,
+//! 	take_while,
+//! 	take_while_parser::TakeWhileParser,
+//! 	sequence_parser::SequenceParserError,
+//! 	Parseable,
+//! 	Parser,
+//! 	RecordReader,
+//! 	};
+//!
+//! struct Line;
+//!
+//! impl<'a> Parseable<'a> for Line
+//! 	{
+//! 	type Error = SequenceParserError<'a, TakeWhileParser<'a>, StringParser<'a>>;
+//! 	type Output = &'a str;
+//!
+//! 	fn parse(src: &'a str, pos: &mut usize) -> Result<Self::Output, Self::Error>
+//! 		{
+//! 		take_while("line", &|c| c != '\n', 0, usize::MAX)
+//! 			.and_then(string("\n"))
+//! 			.parse(src, pos)
+//! 			.map(|(line, _)| line)
+//! 		}
+//!
+//! 	fn name() -> &'a str
+//! 		{
+//! 		"line"
+//! 		}
+//! 	}
+//!
+//! let mut reader = RecordReader::from_read("GET /\nPUT /item\n".as_bytes());
+//! let mut count = 0;
+//! while let Some(line) = reader.next_record::<Line>().unwrap()
+//! 	{
+//! 	assert!(line.is_ok());
+//! 	count += 1;
+//! 	}
+//! assert_eq!(count, 2);
+//! ```
+//!
+//! # Trivia
+//! [Session::trivia] declares the whitespace and comments of a grammar once,
+//! as a function or closure which advances the position past them.
+//! Token parsers skip trivia before or after themselves: `string`, `insensitive_string`,
+//! `literal_set`, `integer`, `float`, `string_literal`, `backreference`, `end_of_input`
+//! and parsers wrapped by [lexeme] or [Parser::capture].
+//! Parsers of characters, such as `character`, `character_class`, `satisfy`, `any_char`
+//! and `take_while`, never skip trivia because tokens are built from them,
+//! so wrap such a token with [lexeme].
+//! [no_trivia] disables trivia for rules which are whitespace-sensitive.
+//! [Session::parse_with_trivia] returns the ranges of the skipped trivia when they are retained,
+//! leaving out the trivia skipped by branches which backtracked.
+//!
+//! [Session::trivia]: struct.Session.html#method.trivia
+//! [lexeme]: trait.Parser.html#method.lexeme
+//! [Parser::capture]: trait.Parser.html#method.capture
+//! [no_trivia]: trait.Parser.html#method.no_trivia
+//! [Session::parse_with_trivia]: struct.Session.html#method.parse_with_trivia
+//!
+//! ```
+//! use markab_parser::{
+//! 	string,
+//! 	take_while,
+//! 	Parser,
+//! 	Session,
+//! 	TriviaPosition,
+//! 	};
+//!
+//! fn whitespace(src: &str, pos: &mut usize)
+//! {
+//! 	let _ = take_while("whitespace", &char::is_whitespace, 0, usize::MAX).skip(src, pos);
+//! }
+//!
+//! let parser = string("(").and_then(string("x")).and_then(string(")"));
+//! let session = Session::new().trivia(whitespace, TriviaPosition::Before);
+//! let mut pos = 0;
+//! assert!(session.parse(&parser, " ( x )", &mut pos).is_ok());
+//! assert_eq!(pos, 6);
+//! ```
+//!
+//! [line_comment] and [block_comment] parse comments, and block comments may nest.
+//! A closure combines them with whitespace.
+//!
+//! [line_comment]: fn.line_comment.html
+//! [block_comment]: fn.block_comment.html
+//!
+//! ```
+//! use markab_parser::{
+//! 	block_comment,
+//! 	line_comment,
+//! 	string,
+//! 	take_while,
+//! 	Parser,
+//! 	Session,
+//! 	TriviaPosition,
+//! 	};
+//!
+//! let comment = block_comment("(*", "*)").nested(true);
+//! let trivia = move |src: &str, pos: &mut usize| loop {
+//! 	let from = *pos;
+//! 	let _ = take_while("whitespace", &char::is_whitespace, 0, usize::MAX).skip(src, pos);
+//! 	let _ = line_comment("#").skip(src, pos);
+//! 	let _ = comment.skip(src, pos);
+//! 	if *pos == from
+//! 		{
+//! 		break;
+//! 		}
+//! };
+//!
+//! let session = Session::new().trivia(trivia, TriviaPosition::Before);
+//! let mut pos = 0;
+//! assert!(session
+//! 	.parse(&string("x"), " # a\n (* b (* c *) *) x", &mut pos)
+//! 	.is_ok());
+//! assert_eq!(pos, 23);
+//! ```
+//!
+//! # Indentation
+//! [indent] parses a block whose lines are indented deeper than the enclosing block,
+//! [same_indent] consumes the indentation of the next line of the block,
+//! and [dedent] checks that the next line returns to an enclosing block.
+//! The width of tabs is set by [Session::tab_policy].
+//!
+//! [indent]: fn.indent.html
+//! [same_indent]: fn.same_indent.html
+//! [dedent]: fn.dedent.html
+//! [Session::tab_policy]: struct.Session.html#method.tab_policy
+//!
+//! ```
+//! use markab_parser::{
+//! 	dedent,
+//! 	indent,
+//! 	same_indent,
+//! 	string,
+//! 	take_while,
+//! 	Parser,
+//! 	};
+//!
+//! let line = || take_while("line", &|c| c != '\n', 1, usize::MAX).and_then(string("\n"));
+//! let block = line()
+//! 	.and_then(same_indent().and_then(line()).zero_or_more())
+//! 	.and_then(dedent());
+//! let parser = line().and_then(indent(block));
+//! let mut pos = 0;
+//! assert!(parser.parse("if x:\n    a\n    b\nc\n", &mut pos).is_ok());
+//! assert_eq!(pos, 18);
+//! ```
+//!
+//! # User state
+//! [Session::parse_with_state] threads a value through a parse, such as a symbol table.
+//! Parsers read it with [with_state] and change it with [modify_state],
+//! which returns a closure undoing the change.
+//! Changes are undone when a choice, repetition or lookahead backtracks over them.
+//! The state is lent to one closure at a time,
+//! so [with_state] and [modify_state] called from inside those closures find no state.
+//! It must be `'static`: store byte ranges of the source instead of `&str` slices.
+//!
+//! [Session::parse_with_state]: struct.Session.html#method.parse_with_state
+//! [with_state]: fn.with_state.html
+//! [modify_state]: fn.modify_state.html
+//!
+//! ```
+//! use markab_parser::{
+//! 	modify_state,
+//! 	string,
+//! 	Parser,
+//! 	Session,
+//! 	};
+//!
+//! let declare = string("let").map(&|_| {
+//! 	modify_state(|count: &mut usize| {
+//! 		*count += 1;
+//! 		|count: &mut usize| *count -= 1
+//! 	});
+//! });
+//! let parser = declare.and_then(string("!")).or(string("let?"));
+//! let mut pos = 0;
+//! let (result, count) = Session::new().parse_with_state(&parser, "let?", &mut pos, 0usize);
+//! assert!(result.is_ok());
+//! assert_eq!(count, 0);
+//!
+//! let mut pos = 0;
+//! let (_, count) = Session::new().parse_with_state(&parser, "let!", &mut pos, 0usize);
+//! assert_eq!(count, 1);
+//! ```
+//!
+//! # Captures
+//! [Parser::capture] records the slice consumed by a parser under a name,
+//! and [backreference] matches exactly the slice captured last under that name,
+//! which suits closing tags, heredoc terminators and raw string delimiters.
+//! Captures are local to the [Parseable] rule which made them, and are dropped when parsers backtrack.
+//! A rule therefore cannot refer to a capture made by a rule it called,
+//! which lets recursive rules such as nested elements each match their own tags.
+//! Captures are only recorded inside a [Session] or a [Parseable] rule,
+//! and every [Session::run] starts without any.
+//! A backreference without a capture reports that no such capture was made,
+//! and why when it runs outside of both.
+//! Capture names are `&'static str`, so recording a capture does not allocate.
+//!
+//! [Parser::capture]: trait.Parser.html#method.capture
+//! [backreference]: fn.backreference.html
+//! [Parseable]: trait.Parseable.html
+//! [Session::run]: struct.Session.html#method.run
+//!
+//! ```
+//! use markab_parser::{
+//! 	backreference,
+//! 	string,
+//! 	take_while,
+//! 	Parser,
+//! 	Session,
+//! 	};
+//!
+//! let parser = string("<")
+//! 	.and_then(take_while("name", &char::is_alphabetic, 1, usize::MAX).capture("tag"))
+//! 	.and_then(string(">"))
+//! 	.and_then(take_while("text", &|c| c != '<', 0, usize::MAX))
+//! 	.and_then(backreference("tag").label("closing tag").enclosed("</", ">"));
+//! let session = Session::new();
+//! let mut pos = 0;
+//! assert!(session.parse(&parser, "<item>text</item>", &mut pos).is_ok());
+//! let mut pos = 0;
+//! let err = session.parse(&parser, "<item>text</itme>", &mut pos).err().unwrap();
+//! assert!(format!("{}", err).contains(
+//! 	"at position 10 required closing tag `</item>` to match opening at 1:2 but </itme> found."
+//! ));
+//! ```
+//!
+//! # Diagnostics
+//! [Diagnostic] renders an error with its line and column in the source,
+//! and with hints proposing the expected literals closest to the text found.
+//! [to_json] and [to_sarif] export diagnostics for editors and CI tools.
+//! Columns count Unicode code points, starting from 1.
+//! [Diagnostic::colored] renders them with ANSI colors.
+//! [ColorChoice::Auto] disables them when the sink passed by the caller is not a terminal.
+//!
+//! [Diagnostic]: diagnostic/struct.Diagnostic.html
+//! [to_json]: diagnostic/fn.to_json.html
+//! [to_sarif]: diagnostic/fn.to_sarif.html
+//! [Diagnostic::colored]: diagnostic/struct.Diagnostic.html#method.colored
+//! [ColorChoice::Auto]: diagnostic/enum.ColorChoice.html#variant.Auto
+//!
+//! ```
+//! use markab_parser::{
+//! 	diagnostic::{
+//! 		to_sarif,
+//! 		Diagnostic,
+//! 		},
+//! 	string,
+//! 	Parser,
+//! 	};
+//!
+//! let parser = string("include").or(string("import")).or(string("export"));
+//! let src = "inclde <stdio.h>";
+//! let mut pos = 0;
+//! let err = parser.parse(src, &mut pos).err().unwrap();
+//! let diagnostic = Diagnostic::new(&err, src).file("main.c");
+//! assert_eq!(diagnostic.suggestions()[0].literal(), "include");
+//! assert!(format!("{}", diagnostic).starts_with("main.c:1:5: parse error\n"));
+//! assert!(format!("{}", diagnostic).ends_with("hint: did you mean \"include\"?\n"));
+//! let sarif = to_sarif("cc", &[diagnostic]);
+//! assert!(sarif.contains("\"uri\":\"main.c\""));
+//! assert!(sarif.contains("\"columnKind\":\"unicodeCodePoints\""));
+//! ```
+#![allow(clippy::tabs_in_doc_comments)]
+#![forbid(unsafe_code)]
 
 pub mod and_parser;
+pub mod any_char_parser;
+pub mod backreference_parser;
+mod budget;
+pub mod capture_parser;
+mod case_folding;
 pub mod character_class_parser;
 pub mod character_parser;
+pub mod character_set;
+pub mod collect_parser;
+pub mod comment_parser;
+pub mod complete;
+pub mod diagnostic;
+pub mod end_of_input_parser;
 mod equal;
 mod error;
+mod error_limits;
+mod failure;
+pub mod float_parser;
+pub mod fold_parser;
 pub mod gen_parser;
+mod incomplete;
+pub mod indentation;
+pub mod insensitive_character_parser;
+pub mod insensitive_string_parser;
+pub mod integer_parser;
+mod interrupted;
+mod lazy_error;
+pub mod lexeme_parser;
+pub mod literal_set_parser;
 pub mod map_parser;
+mod nesting;
+pub mod no_trivia_parser;
 pub mod not_parser;
+mod nullable;
 pub mod order_parser;
 mod parseable;
 pub mod parseable_parser;
 mod parser;
+mod reader;
 pub mod repetition_parser;
+pub mod satisfy_parser;
+pub mod scan;
 pub mod sequence_parser;
+mod session;
+pub mod string_literal_parser;
 pub mod string_parser;
 pub mod stringify_parser;
+pub mod take_until_parser;
+pub mod take_while_parser;
 
 pub use crate::{
 	and_parser::and,
-	character_class_parser::character_class,
+	any_char_parser::any_char,
+	backreference_parser::backreference,
+	budget::BudgetExhausted,
+	case_folding::CaseFolding,
+	character_class_parser::{
+		character_class,
+		character_set,
+	},
 	character_parser::character,
+	character_set::CharSet,
+	comment_parser::{
+		block_comment,
+		line_comment,
+	},
+	end_of_input_parser::end_of_input,
 	error::Error,
+	error_limits::ErrorLimits,
+	failure::Failure,
+	float_parser::{
+		float,
+		FloatSyntax,
+	},
+	incomplete::{
+		Incomplete,
+		Needed,
+	},
+	indentation::{
+		dedent,
+		indent,
+		same_indent,
+	},
+	insensitive_character_parser::insensitive_character,
+	insensitive_string_parser::insensitive_string,
+	integer_parser::integer,
+	interrupted::Interrupted,
+	lazy_error::LazyError,
+	literal_set_parser::{
+		literal_map,
+		literal_set,
+	},
+	nesting::NestingTooDeep,
 	not_parser::not,
+	nullable::Nullable,
 	parseable::Parseable,
 	parser::Parser,
+	reader::{
+		Record,
+		RecordReader,
+	},
+	satisfy_parser::satisfy,
+	session::{
+		modify_state,
+		with_state,
+		Session,
+		SessionResult,
+		Skipper,
+		TriviaPosition,
+		TriviaRanges,
+	},
+	string_literal_parser::{
+		string_literal,
+		EscapeDialect,
+	},
 	string_parser::string,
+	take_until_parser::take_until,
+	take_while_parser::take_while,
 };
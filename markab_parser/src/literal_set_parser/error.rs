@@ -0,0 +1,97 @@
+use crate::{
+	literal_set_parser::LiteralSetParserRequirement,
+	Error,
+};
+use std::fmt::{
+	Display,
+	Formatter,
+	Result as FmtResult,
+};
+
+#[derive(Debug)]
+pub struct LiteralSetParserError<'a>
+{
+	from: usize,
+	requirement: LiteralSetParserRequirement<'a>,
+	farthest: usize,
+	src: &'a str,
+}
+
+impl<'a> LiteralSetParserError<'a>
+{
+	pub fn new(
+		from: usize,
+		requirement: LiteralSetParserRequirement<'a>,
+		farthest: usize,
+		src: &'a str,
+	) -> Self
+	{
+		Self {
+			from,
+			requirement,
+			farthest,
+			src,
+		}
+	}
+}
+
+impl<'a> Error for LiteralSetParserError<'a>
+{
+	fn position(&self) -> usize
+	{
+		self.from
+	}
+
+	fn from(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "{}", self.from)
+	}
+
+	fn requirement(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "{}", self.requirement)
+	}
+
+	fn result(&self, f: &mut Formatter) -> FmtResult
+	{
+		let to = self.farthest
+			+ self.src[self.farthest..]
+				.chars()
+				.next()
+				.map_or(0, char::len_utf8);
+		if to == self.from
+		{
+			write!(f, "not found")
+		}
+		else
+		{
+			write!(f, "{} found", &self.src[self.from..to])
+		}
+	}
+
+	fn for_each_literal<'s>(&'s self, f: &mut dyn FnMut(&'s str))
+	{
+		for literal in self.requirement.literals()
+		{
+			f(literal)
+		}
+	}
+
+	fn farthest(&self) -> usize
+	{
+		self.farthest
+	}
+
+	fn causes(&self, _: &mut Formatter, _: usize) -> FmtResult
+	{
+		Ok(())
+	}
+}
+
+impl<'a> Display for LiteralSetParserError<'a>
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		self.print(f, 0)
+	}
+}
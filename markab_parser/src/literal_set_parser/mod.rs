@@ -0,0 +1,15 @@
+mod error;
+mod parser;
+mod requirement;
+mod trie;
+mod utility;
+
+pub use self::{
+	error::LiteralSetParserError,
+	parser::LiteralSetParser,
+	requirement::LiteralSetParserRequirement,
+	utility::{
+		literal_map,
+		literal_set,
+	},
+};
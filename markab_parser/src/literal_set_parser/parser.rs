@@ -0,0 +1,192 @@
+use crate::{
+	literal_set_parser::{
+		trie::Trie,
+		LiteralSetParserError,
+		LiteralSetParserRequirement,
+	},
+	session,
+	Failure,
+	Needed,
+	Nullable,
+	Parser,
+};
+use std::{
+	fmt::Debug,
+	sync::Arc,
+};
+
+#[derive(Debug)]
+pub struct LiteralSetParser<'a, T>
+where
+	T: Clone + Debug,
+{
+	literals: Arc<[&'a str]>,
+	values: Vec<T>,
+	trie: Trie,
+}
+
+impl<'a, T> LiteralSetParser<'a, T>
+where
+	T: Clone + Debug,
+{
+	pub fn new<I>(entries: I) -> Self
+	where
+		I: IntoIterator<Item = (&'a str, T)>,
+	{
+		let mut literals = Vec::new();
+		let mut values = Vec::new();
+		let mut trie = Trie::new();
+		for (index, (literal, value)) in entries.into_iter().enumerate()
+		{
+			trie.insert(literal, index);
+			literals.push(literal);
+			values.push(value);
+		}
+		Self {
+			literals: literals.into(),
+			values,
+			trie,
+		}
+	}
+
+	fn find(&self, src: &'a str, from: usize) -> Result<(usize, &T), usize>
+	{
+		let (matched, walked, open) = self.trie.longest_match(&src[from..]);
+		if open
+		{
+			session::reached_end(src, src.len(), Needed::Unknown);
+		}
+		match matched
+		{
+			Some((len, index)) => Ok((from + len, &self.values[index])),
+			None =>
+			{
+				session::record_failure(from, || self.requirement(None));
+				Err(from + walked)
+			}
+		}
+	}
+}
+
+impl<'a, T> Parser<'a> for LiteralSetParser<'a, T>
+where
+	T: Clone + Debug,
+{
+	type Error = LiteralSetParserError<'a>;
+	type Output = (&'a str, T);
+	type Requirement = LiteralSetParserRequirement<'a>;
+	type RequirementContext = ();
+
+	fn parse(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Self::Error>
+	{
+		let src = session::metered(src, *pos);
+		session::lexeme(src, pos, |pos| {
+			let from = *pos;
+			match self.find(src, from)
+			{
+				Ok((end, value)) =>
+				{
+					*pos = end;
+					Ok((&src[from..end], value.clone()))
+				}
+				Err(walked) =>
+				{
+					Err(LiteralSetParserError::new(
+						from,
+						self.requirement(None),
+						walked,
+						src,
+					))
+				}
+			}
+		})
+	}
+
+	fn skip(&self, src: &'a str, pos: &mut usize) -> Result<(), Self::Error>
+	{
+		let src = session::metered(src, *pos);
+		session::lexeme(src, pos, |pos| {
+			let from = *pos;
+			match self.find(src, from)
+			{
+				Ok((end, _)) =>
+				{
+					*pos = end;
+					Ok(())
+				}
+				Err(walked) =>
+				{
+					Err(LiteralSetParserError::new(
+						from,
+						self.requirement(None),
+						walked,
+						src,
+					))
+				}
+			}
+		})
+	}
+
+	fn attempt(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Failure<'a>>
+	{
+		let src = session::metered(src, *pos);
+		session::lexeme(src, pos, |pos| {
+			let from = *pos;
+			match self.find(src, from)
+			{
+				Ok((end, value)) =>
+				{
+					*pos = end;
+					Ok((&src[from..end], value.clone()))
+				}
+				Err(_) => Err(Failure::new(from)),
+			}
+		})
+	}
+
+	fn nullable(&self) -> Nullable
+	{
+		self.literals
+			.iter()
+			.any(|literal| literal.is_empty())
+			.into()
+	}
+
+	fn cheap_attempt(&self) -> bool
+	{
+		true
+	}
+
+	fn requirement(&self, _: Option<&Self::RequirementContext>) -> Self::Requirement
+	{
+		LiteralSetParserRequirement::new(self.literals.clone())
+	}
+}
+
+#[test]
+fn test()
+{
+	use crate::{
+		diagnostic::Diagnostic,
+		literal_set,
+	};
+
+	let parser = literal_set(&["<", "<=", "<<", "<<=", "="]);
+	let mut pos = 0;
+	assert_eq!(parser.parse("<<= 1", &mut pos).ok(), Some(("<<=", 3)));
+	let mut pos = 0;
+	assert_eq!(parser.parse("<> 1", &mut pos).ok(), Some(("<", 0)));
+	let mut pos = 0;
+	assert_eq!(
+		format!("{}", parser.parse("> 1", &mut pos).err().unwrap()),
+		"at position 0 required < / <= / << / <<= / = but > found.\n"
+	);
+
+	let keyword = literal_set(&["select", "insert", "update", "delete"]);
+	let src = "selcet * from t";
+	let mut pos = 0;
+	let err = keyword.parse(src, &mut pos).err().unwrap();
+	let suggestions = Diagnostic::new(&err, src).suggestions();
+	assert_eq!(suggestions.len(), 1);
+	assert_eq!(suggestions[0].literal(), "select");
+}
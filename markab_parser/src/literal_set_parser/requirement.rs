@@ -0,0 +1,43 @@
+use std::{
+	fmt::{
+		Display,
+		Formatter,
+		Result as FmtResult,
+	},
+	sync::Arc,
+};
+
+#[derive(Debug, Clone)]
+pub struct LiteralSetParserRequirement<'a>
+{
+	literals: Arc<[&'a str]>,
+}
+
+impl<'a> LiteralSetParserRequirement<'a>
+{
+	pub fn new(literals: Arc<[&'a str]>) -> Self
+	{
+		Self { literals }
+	}
+
+	pub fn literals(&self) -> &[&'a str]
+	{
+		&self.literals
+	}
+}
+
+impl<'a> Display for LiteralSetParserRequirement<'a>
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		for (i, literal) in self.literals.iter().enumerate()
+		{
+			if i != 0
+			{
+				write!(f, " / ")?;
+			}
+			write!(f, "{}", literal)?;
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,80 @@
+#[derive(Debug, Default)]
+struct Node
+{
+	edges: Vec<(u8, usize)>,
+	index: Option<usize>,
+}
+
+#[derive(Debug)]
+pub struct Trie
+{
+	nodes: Vec<Node>,
+}
+
+impl Trie
+{
+	pub fn new() -> Self
+	{
+		Self {
+			nodes: vec![Node::default()],
+		}
+	}
+
+	pub fn insert(&mut self, literal: &str, index: usize)
+	{
+		let mut node = 0;
+		for byte in literal.bytes()
+		{
+			node = match self.nodes[node]
+				.edges
+				.binary_search_by_key(&byte, |(b, _)| *b)
+			{
+				Ok(i) => self.nodes[node].edges[i].1,
+				Err(i) =>
+				{
+					let next = self.nodes.len();
+					self.nodes.push(Node::default());
+					self.nodes[node].edges.insert(i, (byte, next));
+					next
+				}
+			};
+		}
+		if self.nodes[node].index.is_none()
+		{
+			self.nodes[node].index = Some(index);
+		}
+	}
+
+	pub fn longest_match(&self, src: &str) -> (Option<(usize, usize)>, usize, bool)
+	{
+		let mut node = 0;
+		let mut matched = self.nodes[0].index.map(|index| (0, index));
+		let mut walked = 0;
+		let mut stopped = false;
+		for byte in src.bytes()
+		{
+			match self.nodes[node]
+				.edges
+				.binary_search_by_key(&byte, |(b, _)| *b)
+			{
+				Ok(i) => node = self.nodes[node].edges[i].1,
+				Err(_) =>
+				{
+					stopped = true;
+					break;
+				}
+			}
+			walked += 1;
+			if let Some(index) = self.nodes[node].index
+			{
+				matched = Some((walked, index));
+			}
+		}
+		while !src.is_char_boundary(walked)
+		{
+			walked -= 1;
+		}
+		let open = !stopped && !self.nodes[node].edges.is_empty();
+		(matched, walked, open)
+	}
+}
@@ -0,0 +1,15 @@
+use crate::literal_set_parser::LiteralSetParser;
+use std::fmt::Debug;
+
+pub fn literal_set<'a>(literals: &[&'a str]) -> LiteralSetParser<'a, usize>
+{
+	LiteralSetParser::new(literals.iter().copied().zip(0..))
+}
+
+pub fn literal_map<'a, T, I>(entries: I) -> LiteralSetParser<'a, T>
+where
+	T: Clone + Debug,
+	I: IntoIterator<Item = (&'a str, T)>,
+{
+	LiteralSetParser::new(entries)
+}
@@ -3,6 +3,8 @@ use crate::{
 		MapParserError,
 		MapParserRequirement,
 	},
+	Failure,
+	Nullable,
 	Parser,
 };
 use std::fmt::{
@@ -16,14 +18,14 @@ where
 	P: 'a + Parser<'a>,
 {
 	requirement: P,
-	mapper: &'a Fn(P::Output) -> R,
+	mapper: &'a dyn Fn(P::Output) -> R,
 }
 
 impl<'a, P, R> MapParser<'a, P, R>
 where
 	P: Parser<'a>,
 {
-	pub fn new(requirement: P, mapper: &'a Fn(P::Output) -> R) -> Self
+	pub fn new(requirement: P, mapper: &'a dyn Fn(P::Output) -> R) -> Self
 	{
 		Self {
 			requirement,
@@ -71,6 +73,30 @@ where
 			.map_err(|err| MapParserError::new(from, self.requirement(None), err))
 	}
 
+	fn attempt(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Failure<'a>>
+	{
+		let from = *pos;
+		self.requirement
+			.attempt(src, pos)
+			.map(|res| (self.mapper)(res))
+			.map_err(|failure| failure.at(from))
+	}
+
+	fn nullable(&self) -> Nullable
+	{
+		self.requirement.nullable()
+	}
+
+	fn next_candidate(&self, src: &'a str, from: usize) -> Option<usize>
+	{
+		self.requirement.next_candidate(src, from)
+	}
+
+	fn cheap_attempt(&self) -> bool
+	{
+		self.requirement.cheap_attempt()
+	}
+
 	fn requirement(&self, _: Option<&Self::RequirementContext>) -> Self::Requirement
 	{
 		MapParserRequirement::new(self.requirement.requirement(None))
@@ -86,4 +112,8 @@ fn test()
 	let src = "test";
 	let mut pos = 0;
 	let res = parser.parse(src, &mut pos);
+	assert_eq!(res.ok(), Some("test".to_owned()));
+	pos = 0;
+	let res = parser.parse_lazy("tset", &mut pos);
+	assert_eq!(res.err().map(|err| err.failure().from()), Some(0));
 }
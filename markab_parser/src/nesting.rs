@@ -0,0 +1,72 @@
+use crate::Error;
+use std::fmt::{
+	Display,
+	Formatter,
+	Result as FmtResult,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NestingTooDeep
+{
+	offset: usize,
+	limit: usize,
+}
+
+impl NestingTooDeep
+{
+	pub fn new(offset: usize, limit: usize) -> Self
+	{
+		Self { offset, limit }
+	}
+
+	pub fn offset(&self) -> usize
+	{
+		self.offset
+	}
+
+	pub fn limit(&self) -> usize
+	{
+		self.limit
+	}
+}
+
+impl Error for NestingTooDeep
+{
+	fn position(&self) -> usize
+	{
+		self.offset
+	}
+
+	fn from(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "{}", self.offset)
+	}
+
+	fn requirement(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "at most {} nested rules", self.limit)
+	}
+
+	fn result(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "nesting too deep")
+	}
+
+	fn farthest(&self) -> usize
+	{
+		self.offset
+	}
+
+	fn causes(&self, _: &mut Formatter, _: usize) -> FmtResult
+	{
+		Ok(())
+	}
+}
+
+impl Display for NestingTooDeep
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		self.print(f, 0)
+	}
+}
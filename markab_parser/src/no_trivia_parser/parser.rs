@@ -0,0 +1,72 @@
+use crate::{
+	session::TriviaGuard,
+	Failure,
+	Nullable,
+	Parser,
+};
+use std::marker::PhantomData;
+
+#[derive(Debug)]
+pub struct NoTriviaParser<'a, P>
+where
+	P: Parser<'a>,
+{
+	requirement: P,
+	_a: PhantomData<&'a ()>,
+}
+
+impl<'a, P> NoTriviaParser<'a, P>
+where
+	P: Parser<'a>,
+{
+	pub fn new(requirement: P) -> Self
+	{
+		Self {
+			requirement,
+			_a: PhantomData,
+		}
+	}
+}
+
+impl<'a, P> Parser<'a> for NoTriviaParser<'a, P>
+where
+	P: Parser<'a>,
+{
+	type Error = P::Error;
+	type Output = P::Output;
+	type Requirement = P::Requirement;
+	type RequirementContext = P::RequirementContext;
+
+	fn parse(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Self::Error>
+	{
+		let _guard = TriviaGuard::disable();
+		self.requirement.parse(src, pos)
+	}
+
+	fn skip(&self, src: &'a str, pos: &mut usize) -> Result<(), Self::Error>
+	{
+		let _guard = TriviaGuard::disable();
+		self.requirement.skip(src, pos)
+	}
+
+	fn attempt(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Failure<'a>>
+	{
+		let _guard = TriviaGuard::disable();
+		self.requirement.attempt(src, pos)
+	}
+
+	fn nullable(&self) -> Nullable
+	{
+		self.requirement.nullable()
+	}
+
+	fn cheap_attempt(&self) -> bool
+	{
+		self.requirement.cheap_attempt()
+	}
+
+	fn requirement(&self, context: Option<&Self::RequirementContext>) -> Self::Requirement
+	{
+		self.requirement.requirement(context)
+	}
+}
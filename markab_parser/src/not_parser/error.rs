@@ -42,6 +42,11 @@ impl<'a, P> Error for NotParserError<'a, P>
 where
 	P: Parser<'a>,
 {
+	fn position(&self) -> usize
+	{
+		self.from
+	}
+
 	fn from(&self, f: &mut Formatter) -> FmtResult
 	{
 		write!(f, "{}", self.from)
@@ -57,6 +62,11 @@ where
 		write!(f, "success to parse")
 	}
 
+	fn farthest(&self) -> usize
+	{
+		self.from
+	}
+
 	fn causes(&self, _: &mut Formatter, _: usize) -> FmtResult
 	{
 		Ok(())
@@ -3,6 +3,9 @@ use crate::{
 		NotParserError,
 		NotParserRequirement,
 	},
+	session,
+	Failure,
+	Nullable,
 	Parser,
 };
 use std::marker::PhantomData;
@@ -41,17 +44,45 @@ where
 	fn parse(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Self::Error>
 	{
 		let from = *pos;
+		let checkpoint = session::checkpoint();
 		match self.requirement.parse(src, pos)
 		{
 			Ok(res) =>
 			{
 				*pos = from;
+				session::rollback(checkpoint);
 				Err(NotParserError::new(from, self.requirement(None), res))
 			}
 			Err(err) => Ok(err),
 		}
 	}
 
+	fn attempt(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Failure<'a>>
+	{
+		let from = *pos;
+		let checkpoint = session::checkpoint();
+		match self.requirement.parse(src, pos)
+		{
+			Ok(_) =>
+			{
+				*pos = from;
+				session::rollback(checkpoint);
+				Err(Failure::new(from))
+			}
+			Err(err) => Ok(err),
+		}
+	}
+
+	fn nullable(&self) -> Nullable
+	{
+		Nullable::Yes
+	}
+
+	fn cheap_attempt(&self) -> bool
+	{
+		self.requirement.cheap_attempt()
+	}
+
 	fn requirement(&self, _: Option<&Self::RequirementContext>) -> Self::Requirement
 	{
 		NotParserRequirement::new(self.requirement.requirement(None))
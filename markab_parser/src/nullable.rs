@@ -0,0 +1,50 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Nullable
+{
+	No,
+	Unknown,
+	Yes,
+}
+
+impl Nullable
+{
+	pub fn then(self, next: Self) -> Self
+	{
+		match (self, next)
+		{
+			(Nullable::No, _) | (_, Nullable::No) => Nullable::No,
+			(Nullable::Yes, Nullable::Yes) => Nullable::Yes,
+			_ => Nullable::Unknown,
+		}
+	}
+
+	pub fn or(self, other: Self) -> Self
+	{
+		match (self, other)
+		{
+			(Nullable::Yes, _) | (_, Nullable::Yes) => Nullable::Yes,
+			(Nullable::No, Nullable::No) => Nullable::No,
+			_ => Nullable::Unknown,
+		}
+	}
+
+	pub fn is_possible(self) -> bool
+	{
+		self != Nullable::No
+	}
+}
+
+impl From<bool> for Nullable
+{
+	fn from(nullable: bool) -> Self
+	{
+		if nullable
+		{
+			Nullable::Yes
+		}
+		else
+		{
+			Nullable::No
+		}
+	}
+}
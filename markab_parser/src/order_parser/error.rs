@@ -1,5 +1,7 @@
 use crate::{
+	error::print_pruned,
 	order_parser::OrderParserRequirement,
+	session,
 	Error,
 	Parser,
 };
@@ -17,7 +19,9 @@ where
 {
 	from: usize,
 	requirement: OrderParserRequirement<'a, P1, P2>,
-	cause: (P1::Error, P2::Error),
+	cause: (Option<P1::Error>, Option<P2::Error>),
+	farthest: usize,
+	pruned: bool,
 }
 
 impl<'a, P1, P2> OrderParserError<'a, P1, P2>
@@ -31,12 +35,49 @@ where
 		cause: (P1::Error, P2::Error),
 	) -> Self
 	{
+		let (first, second) = cause;
+		let farthest = first.farthest().max(second.farthest());
+		let limits = session::error_limits();
+		let cause = if limits.is_farthest_only()
+			|| first.alternatives() + second.alternatives() > limits.get_max_alternatives()
+		{
+			if first.farthest() >= second.farthest()
+			{
+				(Some(first), None)
+			}
+			else
+			{
+				(None, Some(second))
+			}
+		}
+		else
+		{
+			(Some(first), Some(second))
+		};
+		let pruned = cause.0.is_none() || cause.1.is_none();
 		Self {
 			from,
 			requirement,
 			cause,
+			farthest,
+			pruned,
 		}
 	}
+
+	pub fn first(&self) -> Option<&P1::Error>
+	{
+		self.cause.0.as_ref()
+	}
+
+	pub fn second(&self) -> Option<&P2::Error>
+	{
+		self.cause.1.as_ref()
+	}
+
+	pub fn is_pruned(&self) -> bool
+	{
+		self.pruned
+	}
 }
 
 impl<'a, P1, P2> Error for OrderParserError<'a, P1, P2>
@@ -44,6 +85,11 @@ where
 	P1: Parser<'a>,
 	P2: Parser<'a>,
 {
+	fn position(&self) -> usize
+	{
+		self.from
+	}
+
 	fn from(&self, f: &mut Formatter) -> FmtResult
 	{
 		write!(f, "{}", self.from)
@@ -59,10 +105,44 @@ where
 		write!(f, "failed to parse")
 	}
 
+	fn farthest(&self) -> usize
+	{
+		self.farthest
+	}
+
+	fn alternatives(&self) -> usize
+	{
+		self.cause.0.as_ref().map_or(0, |err| err.alternatives())
+			+ self.cause.1.as_ref().map_or(0, |err| err.alternatives())
+	}
+
 	fn causes(&self, f: &mut Formatter, depth: usize) -> FmtResult
 	{
-		self.cause.0.print(f, depth)?;
-		self.cause.1.print(f, depth)
+		if let Some(first) = &self.cause.0
+		{
+			first.print(f, depth)?;
+		}
+		if let Some(second) = &self.cause.1
+		{
+			second.print(f, depth)?;
+		}
+		if self.pruned
+		{
+			print_pruned(f, depth)?;
+		}
+		Ok(())
+	}
+
+	fn for_each_cause<'s>(&'s self, f: &mut dyn FnMut(&'s dyn Error))
+	{
+		if let Some(first) = &self.cause.0
+		{
+			f(first);
+		}
+		if let Some(second) = &self.cause.1
+		{
+			f(second);
+		}
 	}
 }
 
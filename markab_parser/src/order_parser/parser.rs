@@ -5,6 +5,9 @@ use crate::{
 		OrderParserError,
 		OrderParserRequirement,
 	},
+	session,
+	Failure,
+	Nullable,
 	Parser,
 };
 use either::{
@@ -71,11 +74,28 @@ where
 	fn parse(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Self::Error>
 	{
 		let from = *pos;
+		let checkpoint = session::checkpoint();
+		// Replaying a branch whose `attempt` calls `parse` would double the work at each level.
+		if self.cheap_attempt()
+		{
+			if let Ok(res) = self.first.attempt(src, pos)
+			{
+				return Ok(Left(res));
+			}
+			session::rollback(checkpoint);
+			if let Ok(res) = self.second.attempt(src, pos)
+			{
+				return Ok(Right(res));
+			}
+			session::rollback(checkpoint);
+			*pos = from;
+		}
 		let first = match self.first.parse(src, pos)
 		{
 			Ok(res) => return Ok(Left(res)),
 			Err(err) => err,
 		};
+		session::rollback(checkpoint);
 		let second = match self.second.parse(src, pos)
 		{
 			Ok(res) => return Ok(Right(res)),
@@ -91,11 +111,13 @@ where
 	fn skip(&self, src: &'a str, pos: &mut usize) -> Result<(), Self::Error>
 	{
 		let from = *pos;
+		let checkpoint = session::checkpoint();
 		match self.first.skip(src, pos)
 		{
 			Ok(()) => Ok(()),
 			Err(first) =>
 			{
+				session::rollback(checkpoint);
 				self.second.skip(src, pos).map_err(|second| {
 					OrderParserError::new(from, self.requirement(None), (first, second))
 				})
@@ -103,8 +125,117 @@ where
 		}
 	}
 
+	fn attempt(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Failure<'a>>
+	{
+		let from = *pos;
+		let checkpoint = session::checkpoint();
+		if let Ok(res) = self.first.attempt(src, pos)
+		{
+			return Ok(Left(res));
+		}
+		session::rollback(checkpoint);
+		self.second
+			.attempt(src, pos)
+			.map(Right)
+			.map_err(|_| Failure::new(from))
+	}
+
+	fn nullable(&self) -> Nullable
+	{
+		self.first.nullable().or(self.second.nullable())
+	}
+
+	fn cheap_attempt(&self) -> bool
+	{
+		self.first.cheap_attempt() && self.second.cheap_attempt()
+	}
+
 	fn requirement(&self, _: Option<&Self::RequirementContext>) -> Self::Requirement
 	{
 		OrderParserRequirement::new(self.first.requirement(None), self.second.requirement(None))
 	}
 }
+
+#[test]
+fn test()
+{
+	use crate::{
+		string,
+		string_parser::StringParserError,
+		Error,
+		ErrorLimits,
+		Parseable,
+		Session,
+	};
+	use std::cell::Cell;
+	let parser = string("include").or(string("import")).or(string("export"));
+	let mut pos = 0;
+	let err = parser.parse("impart", &mut pos).err().unwrap();
+	assert_eq!(err.alternatives(), 3);
+	assert_eq!(err.farthest(), 3);
+
+	let session = Session::new().error_limits(ErrorLimits::new().max_alternatives(2));
+	let err = session
+		.parse(&parser, "impart", &mut pos)
+		.err()
+		.and_then(|err| err.left())
+		.unwrap();
+	assert_eq!(err.alternatives(), 2);
+	assert!(err.is_pruned());
+	assert!(format!("{}", err).ends_with("\t(some causes were pruned)\n"));
+
+	let parser = string("export").or(string("include")).or(string("import"));
+	let session = Session::new().error_limits(ErrorLimits::new().max_alternatives(1));
+	let err = session
+		.parse(&parser, "impart", &mut pos)
+		.err()
+		.and_then(|err| err.left())
+		.unwrap();
+	assert_eq!(err.alternatives(), 1);
+	assert_eq!(err.farthest(), 3);
+	assert!(err.second().is_some());
+
+	let session = Session::new().error_limits(ErrorLimits::new().farthest_only(true));
+	let err = session.parse(&parser, "impart", &mut pos).err().unwrap();
+	assert_eq!(err.alternatives(), 1);
+	assert_eq!(err.farthest(), 3);
+
+	thread_local! {
+		static CALLS: Cell<usize> = const { Cell::new(0) };
+	}
+
+	struct Leaf;
+
+	impl<'a> Parseable<'a> for Leaf
+	{
+		type Error = StringParserError<'a>;
+		type Output = &'a str;
+
+		fn parse(src: &'a str, pos: &mut usize) -> Result<Self::Output, Self::Error>
+		{
+			CALLS.with(|calls| calls.set(calls.get() + 1));
+			string("x").parse(src, pos)
+		}
+
+		fn name() -> &'a str
+		{
+			"leaf"
+		}
+	}
+
+	macro_rules! tree {
+		() => {
+			Leaf::get_parser()
+		};
+		($depth:tt $($rest:tt)*) => {
+			tree!($($rest)*).or(tree!($($rest)*))
+		};
+	}
+
+	let parser = tree!(1 2 3 4 5 6 7 8);
+	let mut pos = 0;
+	assert!(parser.parse("y", &mut pos).is_err());
+	assert_eq!(CALLS.with(Cell::get), 256);
+	assert!(parser.attempt("y", &mut pos).is_err());
+	assert_eq!(CALLS.with(Cell::get), 512);
+}
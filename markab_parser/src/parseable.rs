@@ -1,6 +1,10 @@
 use crate::{
+	complete::CompleteError,
 	parseable_parser::ParseableParser,
 	Error,
+	Failure,
+	Nullable,
+	Parser,
 };
 
 pub trait Parseable<'a>
@@ -15,6 +19,29 @@ pub trait Parseable<'a>
 		Self::parse(src, pos).map(|_| ())
 	}
 
+	fn attempt(src: &'a str, pos: &mut usize) -> Result<Self::Output, Failure<'a>>
+	{
+		let from = *pos;
+		Self::parse(src, pos).map_err(|_| Failure::named(from, Self::name()))
+	}
+
+	fn cheap_attempt() -> bool
+	{
+		false
+	}
+
+	fn parse_str(src: &'a str) -> Result<Self::Output, CompleteError<'a, ParseableParser<'a, Self>>>
+	where
+		Self: Sized,
+	{
+		Self::get_parser().parse_complete(src)
+	}
+
+	fn nullable() -> Nullable
+	{
+		Nullable::Unknown
+	}
+
 	fn name() -> &'a str;
 
 	fn get_parser() -> ParseableParser<'a, Self>
@@ -1,4 +1,6 @@
 use crate::{
+	error::print_pruned,
+	session,
 	Error,
 	Parseable,
 };
@@ -14,7 +16,9 @@ where
 {
 	from: usize,
 	requirement: &'a str,
-	cause: P::Error,
+	cause: Option<P::Error>,
+	too_deep: Option<usize>,
+	farthest: usize,
 }
 
 impl<'a, P> ParseableParserError<'a, P>
@@ -23,18 +27,55 @@ where
 {
 	pub fn new(from: usize, requirement: &'a str, cause: P::Error) -> Self
 	{
+		let farthest = cause.farthest();
+		let cause = if session::rule_depth() > session::error_limits().get_max_depth()
+		{
+			None
+		}
+		else
+		{
+			Some(cause)
+		};
 		Self {
 			from,
 			requirement,
 			cause,
+			too_deep: None,
+			farthest,
 		}
 	}
+
+	pub fn too_deep(from: usize, requirement: &'a str) -> Self
+	{
+		Self {
+			from,
+			requirement,
+			cause: None,
+			too_deep: session::max_nesting(),
+			farthest: from,
+		}
+	}
+
+	pub fn is_too_deep(&self) -> bool
+	{
+		self.too_deep.is_some()
+	}
+
+	pub fn cause(&self) -> Option<&P::Error>
+	{
+		self.cause.as_ref()
+	}
 }
 
 impl<'a, P> Error for ParseableParserError<'a, P>
 where
 	P: Parseable<'a>,
 {
+	fn position(&self) -> usize
+	{
+		self.from
+	}
+
 	fn from(&self, f: &mut Formatter) -> FmtResult
 	{
 		write!(f, "{}", self.from)
@@ -47,12 +88,34 @@ where
 
 	fn result(&self, f: &mut Formatter) -> FmtResult
 	{
-		write!(f, "failed to parse")
+		match self.too_deep
+		{
+			Some(max_nesting) => write!(f, "nesting too deep (limit {})", max_nesting),
+			None => write!(f, "failed to parse"),
+		}
+	}
+
+	fn farthest(&self) -> usize
+	{
+		self.farthest
 	}
 
 	fn causes(&self, f: &mut Formatter, depth: usize) -> FmtResult
 	{
-		self.cause.print(f, depth)
+		match (&self.cause, self.too_deep)
+		{
+			(Some(cause), _) => cause.print(f, depth),
+			(None, Some(_)) => Ok(()),
+			(None, None) => print_pruned(f, depth),
+		}
+	}
+
+	fn for_each_cause<'s>(&'s self, f: &mut dyn FnMut(&'s dyn Error))
+	{
+		if let Some(cause) = &self.cause
+		{
+			f(cause);
+		}
 	}
 }
 
@@ -1,5 +1,11 @@
 use crate::{
 	parseable_parser::ParseableParserError,
+	session::{
+		self,
+		RuleGuard,
+	},
+	Failure,
+	Nullable,
 	Parseable,
 	Parser,
 };
@@ -33,6 +39,16 @@ where
 	}
 }
 
+impl<'a, P> Default for ParseableParser<'a, P>
+where
+	P: Parseable<'a>,
+{
+	fn default() -> Self
+	{
+		Self::new()
+	}
+}
+
 impl<'a, P> Debug for ParseableParser<'a, P>
 where
 	P: Parseable<'a>,
@@ -57,13 +73,36 @@ where
 	fn parse(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Self::Error>
 	{
 		let from = *pos;
-		P::parse(src, pos).map_err(|err| ParseableParserError::new(from, P::name(), err))
+		let _rule = RuleGuard::enter(from)
+			.ok_or_else(|| ParseableParserError::too_deep(from, P::name()))?;
+		session::grow_stack(|| P::parse(src, pos))
+			.map_err(|err| ParseableParserError::new(from, P::name(), err))
 	}
 
 	fn skip(&self, src: &'a str, pos: &mut usize) -> Result<(), Self::Error>
 	{
 		let from = *pos;
-		P::skip(src, pos).map_err(|err| ParseableParserError::new(from, P::name(), err))
+		let _rule = RuleGuard::enter(from)
+			.ok_or_else(|| ParseableParserError::too_deep(from, P::name()))?;
+		session::grow_stack(|| P::skip(src, pos))
+			.map_err(|err| ParseableParserError::new(from, P::name(), err))
+	}
+
+	fn attempt(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Failure<'a>>
+	{
+		let from = *pos;
+		let _rule = RuleGuard::enter(from).ok_or_else(|| Failure::named(from, P::name()))?;
+		session::grow_stack(|| P::attempt(src, pos)).map_err(|_| Failure::named(from, P::name()))
+	}
+
+	fn nullable(&self) -> Nullable
+	{
+		P::nullable()
+	}
+
+	fn cheap_attempt(&self) -> bool
+	{
+		P::cheap_attempt()
 	}
 
 	fn requirement(&self, _: Option<&Self::RequirementContext>) -> Self::Requirement
@@ -71,3 +110,79 @@ where
 		P::name()
 	}
 }
+
+#[test]
+fn test()
+{
+	use crate::{
+		string,
+		string_parser::StringParserError,
+		Error,
+		Interrupted,
+		NestingTooDeep,
+		Session,
+	};
+
+	struct Parens;
+
+	impl<'a> Parseable<'a> for Parens
+	{
+		type Error = StringParserError<'a>;
+		type Output = ();
+
+		fn parse(src: &'a str, pos: &mut usize) -> Result<Self::Output, Self::Error>
+		{
+			if string("x").skip(src, pos).is_ok()
+			{
+				return Ok(());
+			}
+			string("(").skip(src, pos)?;
+			let inner = *pos;
+			Self::get_parser()
+				.skip(src, pos)
+				.map_err(|_| StringParserError::new(inner, "x", src))?;
+			string(")").skip(src, pos)
+		}
+
+		fn name() -> &'a str
+		{
+			"parens"
+		}
+	}
+
+	let parser = Parens::get_parser();
+	let mut pos = 0;
+	assert!(Session::new()
+		.max_nesting(4)
+		.parse(&parser, "(((x)))", &mut pos)
+		.is_ok());
+	let mut pos = 0;
+	assert!(Session::new()
+		.max_nesting(3)
+		.parse(&parser, "(((x)))", &mut pos)
+		.is_err());
+	let src = "(".repeat(1_000_000);
+	let mut pos = 0;
+	assert_eq!(
+		Session::new()
+			.max_nesting(256)
+			.parse(&parser, &src, &mut pos)
+			.err()
+			.and_then(|err| err.right()),
+		Some(Interrupted::NestingTooDeep(NestingTooDeep::new(256, 256)))
+	);
+	assert_eq!(pos, 0);
+	let session = Session::new().max_nesting(0);
+	let err = session.run(|| parser.parse("x", &mut 0)).err().unwrap();
+	assert!(err.is_too_deep());
+	assert_eq!(err.farthest(), 0);
+	assert_eq!(
+		format!("{}", err),
+		"at position 0 required parens but nesting too deep (limit 0).\n"
+	);
+	let err = session.parse(&parser, "x", &mut 0).err().unwrap();
+	assert_eq!(
+		format!("{}", err),
+		"at position 0 required at most 0 nested rules but nesting too deep.\n"
+	);
+}
@@ -1,18 +1,23 @@
 use crate::{
+	capture_parser::CaptureParser,
+	complete::CompleteError,
 	gen_parser::GenParser,
+	lexeme_parser::LexemeParser,
 	map_parser::MapParser,
+	no_trivia_parser::NoTriviaParser,
 	order_parser::OrderParser,
 	repetition_parser::RepetitionParser,
 	sequence_parser::SequenceParser,
+	session,
 	stringify_parser::StringifyParser,
 	Error,
+	Failure,
+	LazyError,
+	Nullable,
 };
-use std::{
-	fmt::{
-		Debug,
-		Display,
-	},
-	usize::MAX,
+use std::fmt::{
+	Debug,
+	Display,
 };
 
 pub trait Parser<'a>: Debug
@@ -29,6 +34,57 @@ pub trait Parser<'a>: Debug
 		self.parse(src, pos).map(|_| ())
 	}
 
+	fn attempt(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Failure<'a>>
+	{
+		let from = *pos;
+		self.parse(src, pos).map_err(|_| Failure::new(from))
+	}
+
+	fn cheap_attempt(&self) -> bool
+	{
+		false
+	}
+
+	fn parse_lazy(
+		&self,
+		src: &'a str,
+		pos: &mut usize,
+	) -> Result<Self::Output, LazyError<'a, '_, Self>>
+	where
+		Self: Sized,
+	{
+		self.attempt(src, pos)
+			.map_err(|failure| LazyError::new(self, src, failure))
+	}
+
+	fn parse_complete(&self, src: &'a str) -> Result<Self::Output, CompleteError<'a, Self>>
+	where
+		Self: Sized,
+	{
+		let mut pos = 0;
+		match session::track_farthest(|| self.parse(src, &mut pos))
+		{
+			(Err(err), _) => Err(CompleteError::failed(err)),
+			(Ok(res), _) if pos == src.len() => Ok(res),
+			(Ok(_), farthest) => Err(CompleteError::unconsumed(pos, src, farthest)),
+		}
+	}
+
+	fn next_candidate(&self, _src: &'a str, from: usize) -> Option<usize>
+	{
+		Some(from)
+	}
+
+	fn skip_repeated(&self, _src: &'a str, _pos: &mut usize, _max: usize) -> Option<usize>
+	{
+		None
+	}
+
+	fn nullable(&self) -> Nullable
+	{
+		Nullable::Unknown
+	}
+
 	fn requirement(&self, context: Option<&Self::RequirementContext>) -> Self::Requirement;
 
 	fn and_then<P>(self, next: P) -> SequenceParser<'a, Self, P>
@@ -47,14 +103,14 @@ pub trait Parser<'a>: Debug
 		OrderParser::new(self, next)
 	}
 
-	fn map<R>(self, mapper: &'a Fn(Self::Output) -> R) -> MapParser<'a, Self, R>
+	fn map<R>(self, mapper: &'a dyn Fn(Self::Output) -> R) -> MapParser<'a, Self, R>
 	where
 		Self: Sized,
 	{
 		MapParser::new(self, mapper)
 	}
 
-	fn and_gen<P>(self, generator: &'a Fn(&Self::Output) -> P) -> GenParser<'a, Self, P>
+	fn and_gen<P>(self, generator: &'a dyn Fn(&Self::Output) -> P) -> GenParser<'a, Self, P>
 	where
 		Self: Sized,
 		P: Parser<'a>,
@@ -73,14 +129,14 @@ pub trait Parser<'a>: Debug
 	where
 		Self: Sized,
 	{
-		RepetitionParser::new(self, 0, MAX)
+		RepetitionParser::new(self, 0, usize::MAX)
 	}
 
 	fn one_or_more(self) -> RepetitionParser<'a, Self>
 	where
 		Self: Sized,
 	{
-		RepetitionParser::new(self, 1, MAX)
+		RepetitionParser::new(self, 1, usize::MAX)
 	}
 
 	fn stringify(self) -> StringifyParser<'a, Self>
@@ -89,4 +145,25 @@ pub trait Parser<'a>: Debug
 	{
 		StringifyParser::new(self)
 	}
+
+	fn lexeme(self) -> LexemeParser<'a, Self>
+	where
+		Self: Sized,
+	{
+		LexemeParser::new(self)
+	}
+
+	fn no_trivia(self) -> NoTriviaParser<'a, Self>
+	where
+		Self: Sized,
+	{
+		NoTriviaParser::new(self)
+	}
+
+	fn capture(self, name: &'static str) -> CaptureParser<'a, Self>
+	where
+		Self: Sized,
+	{
+		CaptureParser::new(self, name)
+	}
 }
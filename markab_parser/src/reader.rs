@@ -0,0 +1,369 @@
+use crate::{
+	parseable_parser::ParseableParserError,
+	Interrupted,
+	Parseable,
+	Session,
+};
+use either::{
+	Either,
+	Right,
+};
+use std::{
+	io::{
+		BufRead,
+		BufReader,
+		Error as IoError,
+		ErrorKind,
+		Read,
+		Result as IoResult,
+	},
+	str,
+};
+
+pub type Record<'s, P> =
+	Result<<P as Parseable<'s>>::Output, Either<ParseableParserError<'s, P>, Interrupted>>;
+
+const MAX_RECORD_SIZE: usize = 1024 * 1024;
+
+#[derive(Debug)]
+pub struct RecordReader<R>
+where
+	R: BufRead,
+{
+	reader: R,
+	session: Session,
+	max_record_size: usize,
+	buffer: String,
+	pending: Vec<u8>,
+	start: usize,
+	consumed: usize,
+	offset: usize,
+	eof: bool,
+}
+
+impl<R> RecordReader<R>
+where
+	R: BufRead,
+{
+	pub fn new(reader: R) -> Self
+	{
+		Self {
+			reader,
+			session: Session::new(),
+			max_record_size: MAX_RECORD_SIZE,
+			buffer: String::new(),
+			pending: Vec::new(),
+			start: 0,
+			consumed: 0,
+			offset: 0,
+			eof: false,
+		}
+	}
+
+	pub fn session(self, session: Session) -> Self
+	{
+		Self { session, ..self }
+	}
+
+	pub fn max_record_size(self, max_record_size: usize) -> Self
+	{
+		Self {
+			max_record_size,
+			..self
+		}
+	}
+
+	pub fn offset(&self) -> usize
+	{
+		self.offset
+	}
+
+	pub fn next_record<'s, P>(&'s mut self) -> IoResult<Option<Record<'s, P>>>
+	where
+		P: for<'b> Parseable<'b>,
+	{
+		self.advance(0);
+		loop
+		{
+			if self.start == self.buffer.len() && !self.fill()?
+			{
+				return Ok(None);
+			}
+			if self.eof || !self.is_incomplete::<P>()
+			{
+				break;
+			}
+			if self.buffer.len() - self.start >= self.max_record_size
+			{
+				return Err(IoError::new(
+					ErrorKind::InvalidData,
+					format!(
+						"record exceeds the maximum size of {} bytes",
+						self.max_record_size
+					),
+				));
+			}
+			self.fill_more()?;
+		}
+		let src = &self.buffer[self.start..];
+		let mut pos = 0;
+		let result = if self.eof
+		{
+			self.session.parse(&P::get_parser(), src, &mut pos)
+		}
+		else
+		{
+			self.session.parse_partial(&P::get_parser(), src, &mut pos)
+		};
+		match result
+		{
+			Ok(_) if pos == 0 =>
+			{
+				Err(IoError::new(
+					ErrorKind::InvalidInput,
+					"record parser succeeded without consuming input",
+				))
+			}
+			Ok(record) =>
+			{
+				self.consumed = pos;
+				Ok(Some(Ok(record)))
+			}
+			Err(err) => Ok(Some(Err(err))),
+		}
+	}
+
+	pub fn skip_past(&mut self, delimiter: char) -> IoResult<bool>
+	{
+		self.advance(0);
+		loop
+		{
+			if let Some(index) = self.buffer[self.start..].find(delimiter)
+			{
+				self.advance(index + delimiter.len_utf8());
+				return Ok(true);
+			}
+			self.advance(self.buffer.len() - self.start);
+			if !self.fill()?
+			{
+				return Ok(false);
+			}
+		}
+	}
+
+	fn is_incomplete<P>(&self) -> bool
+	where
+		P: for<'b> Parseable<'b>,
+	{
+		let mut pos = 0;
+		matches!(
+			self.session
+				.parse_partial(&P::get_parser(), &self.buffer[self.start..], &mut pos),
+			Err(Right(Interrupted::Incomplete(_)))
+		)
+	}
+
+	fn advance(&mut self, len: usize)
+	{
+		self.start += self.consumed + len;
+		self.offset += self.consumed + len;
+		self.consumed = 0;
+	}
+
+	fn fill_more(&mut self) -> IoResult<()>
+	{
+		let target = ((self.buffer.len() - self.start) * 2).min(self.max_record_size);
+		while self.fill()? && self.buffer.len() - self.start < target
+		{}
+		Ok(())
+	}
+
+	fn fill(&mut self) -> IoResult<bool>
+	{
+		let chunk = self.reader.fill_buf()?;
+		if chunk.is_empty()
+		{
+			self.eof = true;
+			if !self.pending.is_empty()
+			{
+				return Err(IoError::new(
+					ErrorKind::InvalidData,
+					"stream did not end at a character boundary",
+				));
+			}
+			return Ok(false);
+		}
+		let len = chunk.len();
+		self.pending.extend_from_slice(chunk);
+		self.reader.consume(len);
+		let valid = match str::from_utf8(&self.pending)
+		{
+			Ok(text) => text.len(),
+			Err(err) if err.error_len().is_none() => err.valid_up_to(),
+			Err(err) => return Err(IoError::new(ErrorKind::InvalidData, err)),
+		};
+		let text = str::from_utf8(&self.pending[..valid])
+			.map_err(|err| IoError::new(ErrorKind::InvalidData, err))?;
+		// Consumed records are dropped only when the buffer grows,
+		// so a buffer holding many small records is not moved once per record.
+		if self.start * 2 > self.buffer.len() || self.start == self.buffer.len()
+		{
+			self.buffer.drain(..self.start);
+			self.start = 0;
+		}
+		self.buffer.push_str(text);
+		self.pending.drain(..valid);
+		Ok(true)
+	}
+}
+
+impl<R> RecordReader<BufReader<R>>
+where
+	R: Read,
+{
+	pub fn from_read(read: R) -> Self
+	{
+		Self::new(BufReader::new(read))
+	}
+}
+
+#[test]
+fn test()
+{
+	use crate::{
+		sequence_parser::SequenceParserError,
+		string,
+		string_parser::StringParser,
+		take_while,
+		take_while_parser::{
+			TakeWhileParser,
+			TakeWhileParserError,
+		},
+		Parser,
+	};
+
+	struct Line;
+
+	impl<'a> Parseable<'a> for Line
+	{
+		type Error = SequenceParserError<'a, TakeWhileParser<'a>, StringParser<'a>>;
+		type Output = &'a str;
+
+		fn parse(src: &'a str, pos: &mut usize) -> Result<Self::Output, Self::Error>
+		{
+			take_while("line", &|c| c != '\n', 0, usize::MAX)
+				.and_then(string("\n"))
+				.parse(src, pos)
+				.map(|(line, _)| line)
+		}
+
+		fn name() -> &'a str
+		{
+			"line"
+		}
+	}
+
+	let src = "ab\nérable\n\nlast\n".as_bytes();
+	let mut reader = RecordReader::new(BufReader::with_capacity(3, src));
+	let mut lines = vec![];
+	while let Some(line) = reader.next_record::<Line>().unwrap()
+	{
+		lines.push(line.ok().unwrap().to_string());
+	}
+	assert_eq!(lines, vec!["ab", "érable", "", "last"]);
+	assert_eq!(reader.offset(), src.len());
+
+	let mut reader = RecordReader::from_read("ok\nbroken".as_bytes());
+	assert_eq!(
+		reader.next_record::<Line>().unwrap().map(|line| line.ok()),
+		Some(Some("ok"))
+	);
+	assert_eq!(reader.offset(), 0);
+	assert!(matches!(reader.next_record::<Line>(), Ok(Some(Err(_)))));
+	assert_eq!(reader.offset(), 3);
+
+	assert!(!reader.skip_past('\n').unwrap());
+	assert_eq!(reader.offset(), 9);
+	assert!(reader.next_record::<Line>().unwrap().is_none());
+
+	let mut reader = RecordReader::new(BufReader::with_capacity(2, &b"a\n\xff\n"[..]));
+	assert!(reader.next_record::<Line>().is_ok());
+	assert!(reader.next_record::<Line>().is_err());
+
+	let src = "a".repeat(100);
+	let mut reader =
+		RecordReader::new(BufReader::with_capacity(4, src.as_bytes())).max_record_size(16);
+	assert_eq!(
+		reader.next_record::<Line>().err().map(|err| err.kind()),
+		Some(ErrorKind::InvalidData)
+	);
+
+	struct Field;
+
+	impl<'a> Parseable<'a> for Field
+	{
+		type Error = TakeWhileParserError<'a>;
+		type Output = &'a str;
+
+		fn parse(src: &'a str, pos: &mut usize) -> Result<Self::Output, Self::Error>
+		{
+			take_while("field", &|c| c != ',', 0, usize::MAX).parse(src, pos)
+		}
+
+		fn name() -> &'a str
+		{
+			"field"
+		}
+	}
+
+	let mut reader = RecordReader::from_read("ab,cd".as_bytes());
+	assert_eq!(
+		reader
+			.next_record::<Field>()
+			.unwrap()
+			.map(|field| field.ok()),
+		Some(Some("ab"))
+	);
+	assert!(reader.next_record::<Field>().is_err());
+	assert!(reader.skip_past(',').unwrap());
+	assert_eq!(
+		reader
+			.next_record::<Field>()
+			.unwrap()
+			.map(|field| field.ok()),
+		Some(Some("cd"))
+	);
+	assert!(reader.next_record::<Field>().unwrap().is_none());
+
+	struct Empty;
+
+	impl<'a> Parseable<'a> for Empty
+	{
+		type Error = TakeWhileParserError<'a>;
+		type Output = &'a str;
+
+		fn parse(src: &'a str, pos: &mut usize) -> Result<Self::Output, Self::Error>
+		{
+			take_while("digits", &|c| c.is_ascii_digit(), 0, usize::MAX).parse(src, pos)
+		}
+
+		fn name() -> &'a str
+		{
+			"empty"
+		}
+	}
+
+	let mut reader = RecordReader::from_read("12ab".as_bytes());
+	assert_eq!(
+		reader
+			.next_record::<Empty>()
+			.unwrap()
+			.map(|digits| digits.ok()),
+		Some(Some("12"))
+	);
+	assert_eq!(
+		reader.next_record::<Empty>().err().map(|err| err.kind()),
+		Some(ErrorKind::InvalidInput)
+	);
+	assert_eq!(reader.offset(), 2);
+}
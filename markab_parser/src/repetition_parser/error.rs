@@ -44,6 +44,11 @@ impl<'a, P> Error for RepetitionParserError<'a, P>
 where
 	P: Parser<'a>,
 {
+	fn position(&self) -> usize
+	{
+		self.from
+	}
+
 	fn from(&self, f: &mut Formatter) -> FmtResult
 	{
 		write!(f, "{}", self.from)
@@ -59,10 +64,20 @@ where
 		write!(f, "succeed in parsing only {} time(s)", self.found)
 	}
 
+	fn farthest(&self) -> usize
+	{
+		self.cause.farthest()
+	}
+
 	fn causes(&self, f: &mut Formatter, depth: usize) -> FmtResult
 	{
 		self.cause.print(f, depth)
 	}
+
+	fn for_each_cause<'s>(&'s self, f: &mut dyn FnMut(&'s dyn Error))
+	{
+		f(&self.cause)
+	}
 }
 
 impl<'a, P> Display for RepetitionParserError<'a, P>
@@ -1,8 +1,13 @@
 use crate::{
+	collect_parser::CollectParser,
+	fold_parser::FoldParser,
 	repetition_parser::{
 		RepetitionParserError,
 		RepetitionParserRequirement,
 	},
+	session,
+	Failure,
+	Nullable,
 	Parser,
 };
 use std::marker::PhantomData;
@@ -24,6 +29,11 @@ where
 {
 	pub fn new(requirement: P, min: usize, max: usize) -> Self
 	{
+		debug_assert!(
+			max != usize::MAX || requirement.nullable() != Nullable::Yes,
+			"unbounded repetition of {}, which can succeed without consuming input",
+			requirement.requirement(None)
+		);
 		Self {
 			requirement,
 			min,
@@ -31,36 +41,62 @@ where
 			_a: PhantomData,
 		}
 	}
-}
 
-impl<'a, P> Parser<'a> for RepetitionParser<'a, P>
-where
-	P: Parser<'a>,
-{
-	type Error = RepetitionParserError<'a, P>;
-	type Output = Vec<P::Output>;
-	type Requirement = RepetitionParserRequirement<'a, P>;
-	type RequirementContext = ();
+	pub fn fold<A>(self, init: A, folder: &'a dyn Fn(A, P::Output) -> A) -> FoldParser<'a, P, A>
+	where
+		A: Clone,
+	{
+		FoldParser::new(self, init, folder)
+	}
 
-	fn parse(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Self::Error>
+	pub fn count(self) -> FoldParser<'a, P, usize>
+	{
+		self.fold(0, &|count, _| count + 1)
+	}
+
+	pub fn collect<C>(self) -> CollectParser<'a, P, C>
+	where
+		C: Default + Extend<P::Output>,
+	{
+		CollectParser::new(self)
+	}
+
+	pub(crate) fn parse_fold<A, F>(
+		&self,
+		src: &'a str,
+		pos: &mut usize,
+		init: A,
+		mut folder: F,
+	) -> Result<A, RepetitionParserError<'a, P>>
+	where
+		F: FnMut(A, P::Output) -> A,
 	{
 		let from = *pos;
-		let mut result = vec![];
+		let checkpoint = session::checkpoint();
+		let mut result = init;
 		for i in 0..self.min
 		{
-			let res: <P as Parser>::Output = self.requirement.parse(src, pos).map_err(|err| {
+			let res = self.requirement.parse(src, pos).map_err(|err| {
 				*pos = from;
+				session::rollback(checkpoint);
 				RepetitionParserError::new(from, self.requirement(None), i, err)
 			})?;
-			result.push(res)
+			result = folder(result, res);
 		}
 		for _ in self.min..self.max
 		{
-			if let Ok(res) = self.requirement.parse(src, pos)
+			let before = *pos;
+			let checkpoint = session::checkpoint();
+			match self.requirement.attempt(src, pos)
 			{
-				result.push(res)
+				Ok(res) => result = folder(result, res),
+				Err(_) =>
+				{
+					session::rollback(checkpoint);
+					break;
+				}
 			}
-			else
+			if *pos == before
 			{
 				break;
 			}
@@ -68,30 +104,105 @@ where
 		Ok(result)
 	}
 
-	fn skip(&self, src: &'a str, pos: &mut usize) -> Result<(), Self::Error>
+	pub(crate) fn attempt_fold<A, F>(
+		&self,
+		src: &'a str,
+		pos: &mut usize,
+		init: A,
+		mut folder: F,
+	) -> Result<A, Failure<'a>>
+	where
+		F: FnMut(A, P::Output) -> A,
 	{
 		let from = *pos;
-		for i in 0..self.min
+		let checkpoint = session::checkpoint();
+		let mut result = init;
+		for _ in 0..self.min
 		{
-			if let Err(err) = self.requirement.parse(src, pos)
-			{
+			let res = self.requirement.attempt(src, pos).map_err(|failure| {
 				*pos = from;
-				return Err(RepetitionParserError::new(
-					from,
-					self.requirement(None),
-					i,
-					err,
-				));
-			}
+				session::rollback(checkpoint);
+				failure.at(from)
+			})?;
+			result = folder(result, res);
 		}
 		for _ in self.min..self.max
 		{
-			if let Err(_) = self.requirement.parse(src, pos)
+			let before = *pos;
+			let checkpoint = session::checkpoint();
+			match self.requirement.attempt(src, pos)
+			{
+				Ok(res) => result = folder(result, res),
+				Err(_) =>
+				{
+					session::rollback(checkpoint);
+					break;
+				}
+			}
+			if *pos == before
 			{
 				break;
 			}
 		}
-		Ok(())
+		Ok(result)
+	}
+}
+
+impl<'a, P> Parser<'a> for RepetitionParser<'a, P>
+where
+	P: Parser<'a>,
+{
+	type Error = RepetitionParserError<'a, P>;
+	type Output = Vec<P::Output>;
+	type Requirement = RepetitionParserRequirement<'a, P>;
+	type RequirementContext = ();
+
+	fn parse(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Self::Error>
+	{
+		self.parse_fold(src, pos, vec![], |mut result, res| {
+			result.push(res);
+			result
+		})
+	}
+
+	fn skip(&self, src: &'a str, pos: &mut usize) -> Result<(), Self::Error>
+	{
+		let from = *pos;
+		match self.requirement.skip_repeated(src, pos, self.max)
+		{
+			Some(count) if count >= self.min => Ok(()),
+			Some(_) =>
+			{
+				*pos = from;
+				self.parse_fold(src, pos, (), |_, _| ())
+			}
+			None => self.parse_fold(src, pos, (), |_, _| ()),
+		}
+	}
+
+	fn attempt(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Failure<'a>>
+	{
+		self.attempt_fold(src, pos, vec![], |mut result, res| {
+			result.push(res);
+			result
+		})
+	}
+
+	fn nullable(&self) -> Nullable
+	{
+		if self.min == 0
+		{
+			Nullable::Yes
+		}
+		else
+		{
+			self.requirement.nullable()
+		}
+	}
+
+	fn cheap_attempt(&self) -> bool
+	{
+		self.requirement.cheap_attempt()
 	}
 
 	fn requirement(&self, _: Option<&Self::RequirementContext>) -> Self::Requirement
@@ -99,3 +210,81 @@ where
 		RepetitionParserRequirement::new(self.requirement.requirement(None), self.min, self.max)
 	}
 }
+
+#[test]
+fn test()
+{
+	use crate::{
+		modify_state,
+		not,
+		string,
+		with_state,
+		Session,
+	};
+	let parser = not(string("b")).repeat(0, 1_000_000_000);
+	let mut pos = 0;
+	assert_eq!(
+		parser.parse("aaa", &mut pos).map(|res| res.len()).ok(),
+		Some(1)
+	);
+	assert_eq!(pos, 0);
+	assert_eq!(parser.nullable(), Nullable::Yes);
+	assert_eq!(string("a").one_or_more().nullable(), Nullable::No);
+	let parser = crate::character_class(false, &[' ', '\t'], &[]).repeat(2, 3);
+	let mut pos = 0;
+	assert!(parser.skip("  \t x", &mut pos).is_ok());
+	assert_eq!(pos, 3);
+	let mut pos = 0;
+	assert!(parser.skip(" x", &mut pos).is_err());
+	assert_eq!(pos, 0);
+	let parser = not(string("b")).repeat(3, 3);
+	let mut pos = 0;
+	assert_eq!(
+		parser.parse("aaa", &mut pos).map(|res| res.len()).ok(),
+		Some(3)
+	);
+	assert_eq!(
+		parser.attempt("aaa", &mut pos).map(|res| res.len()).ok(),
+		Some(3)
+	);
+	assert!(parser.skip("aaa", &mut pos).is_ok());
+	assert_eq!(pos, 0);
+	if cfg!(debug_assertions)
+	{
+		assert!(std::panic::catch_unwind(|| not(string("b")).zero_or_more()).is_err());
+	}
+	assert_eq!(
+		string("a").zero_or_more().and_then(string("")).nullable(),
+		Nullable::Yes
+	);
+	assert_eq!(crate::backreference("x").nullable(), Nullable::Unknown);
+	assert_eq!(
+		crate::backreference("x").or(string("a")).nullable(),
+		Nullable::Unknown
+	);
+	assert_eq!(
+		string("a").and_then(not(string("b"))).nullable(),
+		Nullable::No
+	);
+	let count = string("a").map(&|_| {
+		modify_state(|count: &mut usize| {
+			*count += 1;
+			|count: &mut usize| *count -= 1
+		});
+	});
+	let parser = count.and_then(string("b")).zero_or_more();
+	let mut pos = 0;
+	let (result, count) = Session::new().parse_with_state(&parser, "ababa", &mut pos, 0usize);
+	assert!(result.is_ok());
+	assert_eq!(pos, 4);
+	assert_eq!(count, 2);
+	let nested = Session::new().run_with_state(0usize, || {
+		modify_state(|count: &mut usize| {
+			assert_eq!(with_state(|count: &usize| *count), None);
+			assert!(!modify_state(|_: &mut usize| |_: &mut usize| ()));
+			*count += 1;
+			|count: &mut usize| *count -= 1
+		})
+	});
+	assert_eq!(nested, (true, 1));
+}
@@ -1,11 +1,8 @@
 use crate::Parser;
-use std::{
-	fmt::{
-		Display,
-		Formatter,
-		Result as FmtResult,
-	},
-	usize::MAX,
+use std::fmt::{
+	Display,
+	Formatter,
+	Result as FmtResult,
 };
 
 #[derive(Debug)]
@@ -41,8 +38,8 @@ where
 		match (self.min, self.max)
 		{
 			(0, 1) => write!(f, "{}?", self.requirement),
-			(0, MAX) => write!(f, "{}*", self.requirement),
-			(1, MAX) => write!(f, "{}+", self.requirement),
+			(0, usize::MAX) => write!(f, "{}*", self.requirement),
+			(1, usize::MAX) => write!(f, "{}+", self.requirement),
 			(i, j) if i == j => write!(f, "{}{{{}}}", self.requirement, i),
 			(i, j) => write!(f, "{}{{{},{}}}", self.requirement, i, j),
 		}
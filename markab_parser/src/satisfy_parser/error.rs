@@ -0,0 +1,71 @@
+use crate::Error;
+use std::fmt::{
+	Display,
+	Formatter,
+	Result as FmtResult,
+};
+
+#[derive(Debug)]
+pub struct SatisfyParserError<'a>
+{
+	from: usize,
+	requirement: &'a str,
+	found: Option<char>,
+}
+
+impl<'a> SatisfyParserError<'a>
+{
+	pub fn new(from: usize, requirement: &'a str, found: Option<char>) -> Self
+	{
+		Self {
+			from,
+			requirement,
+			found,
+		}
+	}
+}
+
+impl<'a> Error for SatisfyParserError<'a>
+{
+	fn position(&self) -> usize
+	{
+		self.from
+	}
+
+	fn from(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "{}", self.from)
+	}
+
+	fn requirement(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "{}", self.requirement)
+	}
+
+	fn result(&self, f: &mut Formatter) -> FmtResult
+	{
+		match self.found
+		{
+			Some(found) => write!(f, "{:?} found", found),
+			None => write!(f, "not found"),
+		}
+	}
+
+	fn farthest(&self) -> usize
+	{
+		self.from
+	}
+
+	fn causes(&self, _: &mut Formatter, _: usize) -> FmtResult
+	{
+		Ok(())
+	}
+}
+
+impl<'a> Display for SatisfyParserError<'a>
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		self.print(f, 0)
+	}
+}
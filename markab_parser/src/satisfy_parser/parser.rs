@@ -0,0 +1,104 @@
+use crate::{
+	satisfy_parser::SatisfyParserError,
+	session,
+	Failure,
+	Needed,
+	Nullable,
+	Parser,
+};
+use std::fmt::{
+	Debug,
+	Formatter,
+	Result as FmtResult,
+};
+
+pub struct SatisfyParser<'a>
+{
+	requirement: &'a str,
+	predicate: &'a dyn Fn(char) -> bool,
+}
+
+impl<'a> SatisfyParser<'a>
+{
+	pub fn new(requirement: &'a str, predicate: &'a dyn Fn(char) -> bool) -> Self
+	{
+		Self {
+			requirement,
+			predicate,
+		}
+	}
+}
+
+impl<'a> Debug for SatisfyParser<'a>
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		f.debug_struct("SatisfyParser")
+			.field("requirement", &self.requirement)
+			.field("predicate", &"..")
+			.finish()
+	}
+}
+
+impl<'a> Parser<'a> for SatisfyParser<'a>
+{
+	type Error = SatisfyParserError<'a>;
+	type Output = &'a str;
+	type Requirement = &'a str;
+	type RequirementContext = ();
+
+	fn parse(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Self::Error>
+	{
+		let src = session::metered(src, *pos);
+		let from = *pos;
+		match src[from..].chars().next()
+		{
+			Some(next) if (self.predicate)(next) =>
+			{
+				*pos += next.len_utf8();
+				Ok(&src[from..*pos])
+			}
+			next =>
+			{
+				session::record_failure(from, || self.requirement);
+				session::reached_end(src, from, Needed::Size(1));
+				Err(SatisfyParserError::new(from, self.requirement, next))
+			}
+		}
+	}
+
+	fn attempt(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Failure<'a>>
+	{
+		let src = session::metered(src, *pos);
+		let from = *pos;
+		match src[from..].chars().next()
+		{
+			Some(next) if (self.predicate)(next) =>
+			{
+				*pos += next.len_utf8();
+				Ok(&src[from..*pos])
+			}
+			_ =>
+			{
+				session::record_failure(from, || self.requirement);
+				session::reached_end(src, from, Needed::Size(1));
+				Err(Failure::new(from))
+			}
+		}
+	}
+
+	fn nullable(&self) -> Nullable
+	{
+		Nullable::No
+	}
+
+	fn cheap_attempt(&self) -> bool
+	{
+		true
+	}
+
+	fn requirement(&self, _: Option<&Self::RequirementContext>) -> Self::Requirement
+	{
+		self.requirement
+	}
+}
@@ -0,0 +1,6 @@
+use crate::satisfy_parser::SatisfyParser;
+
+pub fn satisfy<'a>(requirement: &'a str, predicate: &'a dyn Fn(char) -> bool) -> SatisfyParser<'a>
+{
+	SatisfyParser::new(requirement, predicate)
+}
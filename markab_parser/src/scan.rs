@@ -0,0 +1,78 @@
+#[cfg(feature = "memchr")]
+use memchr::{
+	memchr,
+	memchr2,
+	memchr3,
+	memmem,
+};
+
+#[cfg(feature = "memchr")]
+pub fn find_byte(src: &str, from: usize, byte: u8) -> Option<usize>
+{
+	debug_assert!(byte.is_ascii());
+	memchr(byte, &src.as_bytes()[from..]).map(|i| from + i)
+}
+
+#[cfg(not(feature = "memchr"))]
+pub fn find_byte(src: &str, from: usize, byte: u8) -> Option<usize>
+{
+	debug_assert!(byte.is_ascii());
+	src.as_bytes()[from..]
+		.iter()
+		.position(|&b| b == byte)
+		.map(|i| from + i)
+}
+
+#[cfg(feature = "memchr")]
+pub fn find_byte_set(src: &str, from: usize, bytes: &[u8]) -> Option<usize>
+{
+	debug_assert!(bytes.is_ascii());
+	let haystack = &src.as_bytes()[from..];
+	match *bytes
+	{
+		[] => None,
+		[b1] => memchr(b1, haystack),
+		[b1, b2] => memchr2(b1, b2, haystack),
+		[b1, b2, b3] => memchr3(b1, b2, b3, haystack),
+		_ => haystack.iter().position(|b| bytes.contains(b)),
+	}
+	.map(|i| from + i)
+}
+
+#[cfg(not(feature = "memchr"))]
+pub fn find_byte_set(src: &str, from: usize, bytes: &[u8]) -> Option<usize>
+{
+	debug_assert!(bytes.is_ascii());
+	src.as_bytes()[from..]
+		.iter()
+		.position(|b| bytes.contains(b))
+		.map(|i| from + i)
+}
+
+#[cfg(feature = "memchr")]
+pub fn find_literal(src: &str, from: usize, literal: &str) -> Option<usize>
+{
+	match literal.as_bytes()
+	{
+		[] => Some(from),
+		[byte] => memchr(*byte, &src.as_bytes()[from..]).map(|i| from + i),
+		needle => memmem::find(&src.as_bytes()[from..], needle).map(|i| from + i),
+	}
+}
+
+#[cfg(not(feature = "memchr"))]
+pub fn find_literal(src: &str, from: usize, literal: &str) -> Option<usize>
+{
+	src[from..].find(literal).map(|i| from + i)
+}
+
+#[test]
+fn test()
+{
+	let src = "let s = \"a\\\"é\"; // done";
+	assert_eq!(find_byte(src, 9, b'"'), Some(11));
+	assert_eq!(find_byte_set(src, 9, b"\"\\"), Some(10));
+	assert_eq!(find_literal(src, 0, "//"), Some(17));
+	assert_eq!(find_literal(src, 0, "é\""), Some(12));
+	assert_eq!(find_literal(src, 18, "//"), None);
+}
@@ -49,6 +49,11 @@ where
 	P1: Parser<'a>,
 	P2: Parser<'a>,
 {
+	fn position(&self) -> usize
+	{
+		self.from
+	}
+
 	fn from(&self, f: &mut Formatter) -> FmtResult
 	{
 		write!(f, "{}", self.from)
@@ -68,6 +73,15 @@ where
 		}
 	}
 
+	fn farthest(&self) -> usize
+	{
+		match &self.cause
+		{
+			Left(err) => err.farthest(),
+			Right(err) => err.farthest(),
+		}
+	}
+
 	fn causes(&self, f: &mut Formatter, depth: usize) -> FmtResult
 	{
 		match &self.cause
@@ -76,6 +90,15 @@ where
 			Right(err) => err.print(f, depth),
 		}
 	}
+
+	fn for_each_cause<'s>(&'s self, f: &mut dyn FnMut(&'s dyn Error))
+	{
+		match &self.cause
+		{
+			Left(err) => f(err),
+			Right(err) => f(err),
+		}
+	}
 }
 
 impl<'a, P1, P2> Display for SequenceParserError<'a, P1, P2>
@@ -3,6 +3,9 @@ use crate::{
 		SequenceParserError,
 		SequenceParserRequirement,
 	},
+	session,
+	Failure,
+	Nullable,
 	Parser,
 };
 use either::{
@@ -50,12 +53,14 @@ where
 	fn parse(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Self::Error>
 	{
 		let from = *pos;
+		let checkpoint = session::checkpoint();
 		let first = self
 			.first
 			.parse(src, pos)
 			.map_err(|err| SequenceParserError::new(from, self.requirement(None), Left(err)))?;
 		let second = self.second.parse(src, pos).map_err(|err| {
 			*pos = from;
+			session::rollback(checkpoint);
 			SequenceParserError::new(from, self.requirement(None), Right(err))
 		})?;
 		Ok((first, second))
@@ -64,16 +69,57 @@ where
 	fn skip(&self, src: &'a str, pos: &mut usize) -> Result<(), Self::Error>
 	{
 		let from = *pos;
+		let checkpoint = session::checkpoint();
 		self.first
 			.skip(src, pos)
 			.map_err(|err| SequenceParserError::new(from, self.requirement(None), Left(err)))
 			.and_then(|_| {
 				self.second.skip(src, pos).map_err(|err| {
+					*pos = from;
+					session::rollback(checkpoint);
 					SequenceParserError::new(from, self.requirement(None), Right(err))
 				})
 			})
 	}
 
+	fn attempt(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Failure<'a>>
+	{
+		let from = *pos;
+		let checkpoint = session::checkpoint();
+		let first = self
+			.first
+			.attempt(src, pos)
+			.map_err(|failure| failure.at(from))?;
+		let second = self.second.attempt(src, pos).map_err(|failure| {
+			*pos = from;
+			session::rollback(checkpoint);
+			failure.at(from)
+		})?;
+		Ok((first, second))
+	}
+
+	fn nullable(&self) -> Nullable
+	{
+		self.first.nullable().then(self.second.nullable())
+	}
+
+	fn next_candidate(&self, src: &'a str, from: usize) -> Option<usize>
+	{
+		if self.first.nullable().is_possible()
+		{
+			Some(from)
+		}
+		else
+		{
+			self.first.next_candidate(src, from)
+		}
+	}
+
+	fn cheap_attempt(&self) -> bool
+	{
+		self.first.cheap_attempt() && self.second.cheap_attempt()
+	}
+
 	fn requirement(&self, _: Option<&Self::RequirementContext>) -> Self::Requirement
 	{
 		SequenceParserRequirement::new(self.first.requirement(None), self.second.requirement(None))
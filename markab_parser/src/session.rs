@@ -0,0 +1,926 @@
+use crate::{
+	budget::BudgetExhausted,
+	complete::Farthest,
+	incomplete::{
+		Incomplete,
+		Needed,
+	},
+	indentation::TabPolicy,
+	ErrorLimits,
+	Interrupted,
+	NestingTooDeep,
+	Parser,
+};
+use either::{
+	Either,
+	Left,
+	Right,
+};
+use std::{
+	any::Any,
+	cell::{
+		Cell,
+		RefCell,
+	},
+	fmt::{
+		Debug,
+		Display,
+		Formatter,
+		Result as FmtResult,
+	},
+	ops::Range,
+	sync::Arc,
+};
+
+#[cfg(feature = "stacker")]
+const RED_ZONE: usize = 64 * 1024;
+#[cfg(feature = "stacker")]
+const STACK_SIZE: usize = 1024 * 1024;
+
+thread_local! {
+	static CURRENT: Current = Current::new();
+}
+
+struct Current
+{
+	in_session: Cell<bool>,
+	error_limits: Cell<ErrorLimits>,
+	rule_depth: Cell<usize>,
+	max_nesting: Cell<Option<usize>>,
+	#[cfg(feature = "stacker")]
+	grow_stack: Cell<bool>,
+	tracking: Cell<bool>,
+	farthest: RefCell<Farthest>,
+	trivia: RefCell<Option<(Skipper, TriviaPosition)>>,
+	trivia_enabled: Cell<bool>,
+	retain_trivia: Cell<bool>,
+	retained: RefCell<TriviaRanges>,
+	tab_policy: Cell<TabPolicy>,
+	indents: RefCell<Vec<usize>>,
+	state: RefCell<Option<Box<dyn Any>>>,
+	journal: RefCell<Vec<Undo>>,
+	captures: RefCell<Vec<Capture>>,
+	fuel: Cell<Option<usize>>,
+	budget: Cell<usize>,
+	partial: Cell<bool>,
+	interrupted: Cell<Option<Interrupted>>,
+}
+
+type Undo = Box<dyn FnOnce(&mut dyn Any)>;
+
+type Capture = (&'static str, Range<usize>);
+
+impl Current
+{
+	fn new() -> Self
+	{
+		Self {
+			in_session: Cell::new(false),
+			error_limits: Cell::new(ErrorLimits::new()),
+			rule_depth: Cell::new(0),
+			max_nesting: Cell::new(None),
+			#[cfg(feature = "stacker")]
+			grow_stack: Cell::new(false),
+			tracking: Cell::new(false),
+			farthest: RefCell::new(Farthest::new()),
+			trivia: RefCell::new(None),
+			trivia_enabled: Cell::new(true),
+			retain_trivia: Cell::new(false),
+			retained: RefCell::new(Vec::new()),
+			tab_policy: Cell::new(TabPolicy::default()),
+			indents: RefCell::new(Vec::new()),
+			state: RefCell::new(None),
+			journal: RefCell::new(Vec::new()),
+			captures: RefCell::new(Vec::new()),
+			fuel: Cell::new(None),
+			budget: Cell::new(0),
+			partial: Cell::new(false),
+			interrupted: Cell::new(None),
+		}
+	}
+}
+
+pub type Skipper = Arc<dyn Fn(&str, &mut usize) + Send + Sync>;
+
+pub type TriviaRanges = Vec<Range<usize>>;
+
+pub type SessionResult<'a, P> =
+	Result<<P as Parser<'a>>::Output, Either<<P as Parser<'a>>::Error, Interrupted>>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TriviaPosition
+{
+	Before,
+	After,
+}
+
+#[derive(Clone, Default)]
+pub struct Session
+{
+	error_limits: ErrorLimits,
+	max_nesting: Option<usize>,
+	#[cfg(feature = "stacker")]
+	grow_stack: bool,
+	trivia: Option<(Skipper, TriviaPosition)>,
+	retain_trivia: bool,
+	tab_policy: TabPolicy,
+}
+
+impl Session
+{
+	pub fn new() -> Self
+	{
+		Self {
+			error_limits: ErrorLimits::new(),
+			max_nesting: None,
+			#[cfg(feature = "stacker")]
+			grow_stack: false,
+			trivia: None,
+			retain_trivia: false,
+			tab_policy: TabPolicy::default(),
+		}
+	}
+
+	pub fn error_limits(self, error_limits: ErrorLimits) -> Self
+	{
+		Self {
+			error_limits,
+			..self
+		}
+	}
+
+	pub fn max_nesting(self, max_nesting: usize) -> Self
+	{
+		Self {
+			max_nesting: Some(max_nesting),
+			..self
+		}
+	}
+
+	#[cfg(feature = "stacker")]
+	pub fn grow_stack(self, grow_stack: bool) -> Self
+	{
+		Self { grow_stack, ..self }
+	}
+
+	pub fn trivia<F>(self, skipper: F, position: TriviaPosition) -> Self
+	where
+		F: Fn(&str, &mut usize) + Send + Sync + 'static,
+	{
+		Self {
+			trivia: Some((Arc::new(skipper), position)),
+			..self
+		}
+	}
+
+	pub fn retain_trivia(self, retain_trivia: bool) -> Self
+	{
+		Self {
+			retain_trivia,
+			..self
+		}
+	}
+
+	pub fn tab_policy(self, tab_policy: TabPolicy) -> Self
+	{
+		Self { tab_policy, ..self }
+	}
+
+	pub fn run<R, F>(&self, f: F) -> R
+	where
+		F: FnOnce() -> R,
+	{
+		let _restore = Restore::install(self);
+		f()
+	}
+
+	pub fn run_with_state<S, R, F>(&self, state: S, f: F) -> (R, S)
+	where
+		S: 'static,
+		F: FnOnce() -> R,
+	{
+		self.run(|| {
+			CURRENT.with(|current| current.state.replace(Some(Box::new(state))));
+			let result = f();
+			let state = CURRENT.with(|current| {
+				current.journal.take();
+				current.state.take()
+			});
+			match state.map(|state| state.downcast::<S>())
+			{
+				Some(Ok(state)) => (result, *state),
+				_ => unreachable!("state was replaced while parsing"),
+			}
+		})
+	}
+
+	pub fn parse_with_state<'a, P, S>(
+		&self,
+		parser: &P,
+		src: &'a str,
+		pos: &mut usize,
+		state: S,
+	) -> (SessionResult<'a, P>, S)
+	where
+		P: Parser<'a>,
+		S: 'static,
+	{
+		self.run_with_state(state, || interruptible(parser, src, pos))
+	}
+
+	pub fn parse_partial<'a, P>(
+		&self,
+		parser: &P,
+		src: &'a str,
+		pos: &mut usize,
+	) -> SessionResult<'a, P>
+	where
+		P: Parser<'a>,
+	{
+		self.run(|| {
+			CURRENT.with(|current| current.partial.set(true));
+			interruptible(parser, src, pos)
+		})
+	}
+
+	pub fn parse_with_budget<'a, P>(
+		&self,
+		parser: &P,
+		src: &'a str,
+		pos: &mut usize,
+		budget: usize,
+	) -> SessionResult<'a, P>
+	where
+		P: Parser<'a>,
+	{
+		self.run(|| {
+			CURRENT.with(|current| {
+				current.fuel.set(Some(budget));
+				current.budget.set(budget);
+			});
+			interruptible(parser, src, pos)
+		})
+	}
+
+	pub fn parse<'a, P>(&self, parser: &P, src: &'a str, pos: &mut usize) -> SessionResult<'a, P>
+	where
+		P: Parser<'a>,
+	{
+		self.run(|| interruptible(parser, src, pos))
+	}
+
+	pub fn parse_with_trivia<'a, P>(
+		&self,
+		parser: &P,
+		src: &'a str,
+		pos: &mut usize,
+	) -> (SessionResult<'a, P>, TriviaRanges)
+	where
+		P: Parser<'a>,
+	{
+		self.run(|| {
+			let result = interruptible(parser, src, pos);
+			let mut retained = CURRENT.with(|current| current.retained.take());
+			retained.retain(|range| range.end <= *pos);
+			(result, retained)
+		})
+	}
+}
+
+fn interruptible<'a, P>(parser: &P, src: &'a str, pos: &mut usize) -> SessionResult<'a, P>
+where
+	P: Parser<'a>,
+{
+	let from = *pos;
+	let result = parser.parse(src, pos);
+	match CURRENT.with(|current| current.interrupted.get())
+	{
+		Some(interrupted) =>
+		{
+			*pos = from;
+			Err(Right(interrupted))
+		}
+		None => result.map_err(Left),
+	}
+}
+
+fn interrupt<I>(interrupted: I)
+where
+	I: Into<Interrupted>,
+{
+	CURRENT.with(|current| {
+		if current.interrupted.get().is_none()
+		{
+			current.interrupted.set(Some(interrupted.into()));
+		}
+	})
+}
+
+impl Debug for Session
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		let mut debug = f.debug_struct("Session");
+		debug
+			.field("error_limits", &self.error_limits)
+			.field("max_nesting", &self.max_nesting);
+		#[cfg(feature = "stacker")]
+		debug.field("grow_stack", &self.grow_stack);
+		debug
+			.field(
+				"trivia",
+				&self.trivia.as_ref().map(|(_, position)| position),
+			)
+			.field("retain_trivia", &self.retain_trivia)
+			.field("tab_policy", &self.tab_policy)
+			.finish()
+	}
+}
+
+struct Restore
+{
+	in_session: bool,
+	error_limits: ErrorLimits,
+	rule_depth: usize,
+	max_nesting: Option<usize>,
+	#[cfg(feature = "stacker")]
+	grow_stack: bool,
+	trivia: Option<(Skipper, TriviaPosition)>,
+	trivia_enabled: bool,
+	retain_trivia: bool,
+	retained: TriviaRanges,
+	tab_policy: TabPolicy,
+	indents: Vec<usize>,
+	state: Option<Box<dyn Any>>,
+	journal: Vec<Undo>,
+	captures: Vec<Capture>,
+	fuel: Option<usize>,
+	budget: usize,
+	partial: bool,
+	interrupted: Option<Interrupted>,
+}
+
+impl Restore
+{
+	fn install(session: &Session) -> Self
+	{
+		CURRENT.with(|current| {
+			Self {
+				in_session: current.in_session.replace(true),
+				error_limits: current.error_limits.replace(session.error_limits),
+				rule_depth: current.rule_depth.replace(0),
+				max_nesting: current.max_nesting.replace(session.max_nesting),
+				#[cfg(feature = "stacker")]
+				grow_stack: current.grow_stack.replace(session.grow_stack),
+				trivia: current.trivia.replace(session.trivia.clone()),
+				trivia_enabled: current.trivia_enabled.replace(true),
+				retain_trivia: current.retain_trivia.replace(session.retain_trivia),
+				retained: current.retained.take(),
+				tab_policy: current.tab_policy.replace(session.tab_policy),
+				indents: current.indents.take(),
+				state: current.state.take(),
+				journal: current.journal.take(),
+				captures: current.captures.take(),
+				fuel: current.fuel.replace(None),
+				budget: current.budget.replace(0),
+				partial: current.partial.replace(false),
+				interrupted: current.interrupted.replace(None),
+			}
+		})
+	}
+}
+
+impl Drop for Restore
+{
+	fn drop(&mut self)
+	{
+		CURRENT.with(|current| {
+			current.in_session.set(self.in_session);
+			current.error_limits.set(self.error_limits);
+			current.rule_depth.set(self.rule_depth);
+			current.max_nesting.set(self.max_nesting);
+			#[cfg(feature = "stacker")]
+			current.grow_stack.set(self.grow_stack);
+			current.trivia.replace(self.trivia.take());
+			current.trivia_enabled.set(self.trivia_enabled);
+			current.retain_trivia.set(self.retain_trivia);
+			current.retained.replace(std::mem::take(&mut self.retained));
+			current.tab_policy.set(self.tab_policy);
+			current.indents.replace(std::mem::take(&mut self.indents));
+			current.state.replace(self.state.take());
+			current.journal.replace(std::mem::take(&mut self.journal));
+			current.captures.replace(std::mem::take(&mut self.captures));
+			current.fuel.set(self.fuel);
+			current.budget.set(self.budget);
+			current.partial.set(self.partial);
+			current.interrupted.set(self.interrupted);
+		})
+	}
+}
+
+pub(crate) struct TriviaGuard
+{
+	enabled: bool,
+}
+
+impl TriviaGuard
+{
+	pub(crate) fn disable() -> Self
+	{
+		CURRENT.with(|current| {
+			TriviaGuard {
+				enabled: current.trivia_enabled.replace(false),
+			}
+		})
+	}
+}
+
+impl Drop for TriviaGuard
+{
+	fn drop(&mut self)
+	{
+		CURRENT.with(|current| current.trivia_enabled.set(self.enabled));
+	}
+}
+
+pub(crate) struct RuleGuard
+{
+	captures: usize,
+}
+
+impl RuleGuard
+{
+	pub(crate) fn enter(from: usize) -> Option<Self>
+	{
+		CURRENT.with(|current| {
+			let depth = current.rule_depth.get();
+			if let Some(limit) = current.max_nesting.get().filter(|&limit| depth >= limit)
+			{
+				interrupt(NestingTooDeep::new(from, limit));
+				return None;
+			}
+			current.rule_depth.set(depth + 1);
+			Some(RuleGuard {
+				captures: current.captures.borrow().len(),
+			})
+		})
+	}
+}
+
+impl Drop for RuleGuard
+{
+	fn drop(&mut self)
+	{
+		CURRENT.with(|current| {
+			current.rule_depth.set(current.rule_depth.get() - 1);
+			current.captures.borrow_mut().truncate(self.captures);
+		});
+	}
+}
+
+pub(crate) struct IndentGuard;
+
+impl IndentGuard
+{
+	pub(crate) fn push(width: usize) -> Self
+	{
+		CURRENT.with(|current| current.indents.borrow_mut().push(width));
+		IndentGuard
+	}
+}
+
+impl Drop for IndentGuard
+{
+	fn drop(&mut self)
+	{
+		CURRENT.with(|current| current.indents.borrow_mut().pop());
+	}
+}
+
+pub(crate) struct Snapshot
+{
+	in_session: bool,
+	error_limits: ErrorLimits,
+	rule_depth: usize,
+	max_nesting: Option<usize>,
+	#[cfg(feature = "stacker")]
+	grow_stack: bool,
+	tracking: bool,
+	trivia: Option<(Skipper, TriviaPosition)>,
+	trivia_enabled: bool,
+	retain_trivia: bool,
+	retained: TriviaRanges,
+	tab_policy: TabPolicy,
+	indents: Vec<usize>,
+	captures: Vec<Capture>,
+	fuel: Option<usize>,
+	budget: usize,
+	partial: bool,
+	interrupted: Option<Interrupted>,
+}
+
+impl Snapshot
+{
+	// Captures the settings and scopes which decide how a parser replays from here,
+	// without the budget, retained trivia or farthest tracking of the running parse.
+	pub(crate) fn take() -> Self
+	{
+		CURRENT.with(|current| {
+			Self {
+				in_session: current.in_session.get(),
+				error_limits: current.error_limits.get(),
+				rule_depth: current.rule_depth.get(),
+				max_nesting: current.max_nesting.get(),
+				#[cfg(feature = "stacker")]
+				grow_stack: current.grow_stack.get(),
+				tracking: false,
+				trivia: current.trivia.borrow().clone(),
+				trivia_enabled: current.trivia_enabled.get(),
+				retain_trivia: false,
+				retained: Vec::new(),
+				tab_policy: current.tab_policy.get(),
+				indents: current.indents.borrow().clone(),
+				captures: current.captures.borrow().clone(),
+				fuel: None,
+				budget: 0,
+				partial: false,
+				interrupted: None,
+			}
+		})
+	}
+
+	pub(crate) fn has_state() -> bool
+	{
+		CURRENT.with(|current| current.state.borrow().is_some())
+	}
+
+	// Runs `f` under the snapshot, then undoes its changes to the user state.
+	pub(crate) fn replay<R, F>(&self, f: F) -> R
+	where
+		F: FnOnce() -> R,
+	{
+		let _outer = Replay(self.exchange());
+		let checkpoint = checkpoint();
+		let result = f();
+		rollback(checkpoint);
+		result
+	}
+
+	fn exchange(&self) -> Self
+	{
+		CURRENT.with(|current| {
+			Self {
+				in_session: current.in_session.replace(self.in_session),
+				error_limits: current.error_limits.replace(self.error_limits),
+				rule_depth: current.rule_depth.replace(self.rule_depth),
+				max_nesting: current.max_nesting.replace(self.max_nesting),
+				#[cfg(feature = "stacker")]
+				grow_stack: current.grow_stack.replace(self.grow_stack),
+				tracking: current.tracking.replace(self.tracking),
+				trivia: current.trivia.replace(self.trivia.clone()),
+				trivia_enabled: current.trivia_enabled.replace(self.trivia_enabled),
+				retain_trivia: current.retain_trivia.replace(self.retain_trivia),
+				retained: current.retained.replace(self.retained.clone()),
+				tab_policy: current.tab_policy.replace(self.tab_policy),
+				indents: current.indents.replace(self.indents.clone()),
+				captures: current.captures.replace(self.captures.clone()),
+				fuel: current.fuel.replace(self.fuel),
+				budget: current.budget.replace(self.budget),
+				partial: current.partial.replace(self.partial),
+				interrupted: current.interrupted.replace(self.interrupted),
+			}
+		})
+	}
+}
+
+struct Replay(Snapshot);
+
+impl Drop for Replay
+{
+	fn drop(&mut self)
+	{
+		self.0.exchange();
+	}
+}
+
+pub(crate) fn error_limits() -> ErrorLimits
+{
+	CURRENT.with(|current| current.error_limits.get())
+}
+
+pub(crate) fn rule_depth() -> usize
+{
+	CURRENT.with(|current| current.rule_depth.get())
+}
+
+pub(crate) fn max_nesting() -> Option<usize>
+{
+	CURRENT.with(|current| current.max_nesting.get())
+}
+
+#[cfg(feature = "stacker")]
+pub(crate) fn grow_stack<R, F>(f: F) -> R
+where
+	F: FnOnce() -> R,
+{
+	if CURRENT.with(|current| current.grow_stack.get())
+	{
+		stacker::maybe_grow(RED_ZONE, STACK_SIZE, f)
+	}
+	else
+	{
+		f()
+	}
+}
+
+#[cfg(not(feature = "stacker"))]
+pub(crate) fn grow_stack<R, F>(f: F) -> R
+where
+	F: FnOnce() -> R,
+{
+	f()
+}
+
+pub(crate) fn metered(src: &str, pos: usize) -> &str
+{
+	CURRENT.with(|current| {
+		match current.fuel.get()
+		{
+			None => src,
+			Some(0) =>
+			{
+				interrupt(BudgetExhausted::new(pos, current.budget.get()));
+				&src[..pos]
+			}
+			Some(fuel) =>
+			{
+				current.fuel.set(Some(fuel - 1));
+				src
+			}
+		}
+	})
+}
+
+pub(crate) fn reached_end(src: &str, end: usize, needed: Needed)
+{
+	if end >= src.len() && CURRENT.with(|current| current.partial.get())
+	{
+		interrupt(Incomplete::new(src.len(), needed));
+	}
+}
+
+pub(crate) fn reached_end_of_literal<I, F>(src: &str, from: usize, literal: I, eq: F)
+where
+	I: Iterator<Item = char>,
+	F: Fn(char, char) -> bool,
+{
+	if !CURRENT.with(|current| current.partial.get())
+	{
+		return;
+	}
+	let mut len = 0;
+	let mut found = src[from..].chars();
+	for required in literal
+	{
+		match found.next()
+		{
+			Some(c) if eq(required, c) => (),
+			Some(_) => return,
+			None => len += required.len_utf8(),
+		}
+	}
+	if len > 0
+	{
+		reached_end(src, src.len(), Needed::Size(len));
+	}
+}
+
+pub(crate) fn tab_policy() -> TabPolicy
+{
+	CURRENT.with(|current| current.tab_policy.get())
+}
+
+pub(crate) fn indent_level() -> usize
+{
+	CURRENT.with(|current| current.indents.borrow().last().copied().unwrap_or(0))
+}
+
+pub(crate) fn is_enclosing_indent(width: usize) -> bool
+{
+	CURRENT.with(|current| {
+		let indents = current.indents.borrow();
+		width == 0 || indents[..indents.len().saturating_sub(1)].contains(&width)
+	})
+}
+
+pub(crate) fn record_failure<R, F>(from: usize, requirement: F)
+where
+	R: Display,
+	F: FnOnce() -> R,
+{
+	CURRENT.with(|current| {
+		if current.tracking.get()
+		{
+			current.farthest.borrow_mut().record(from, requirement());
+		}
+	})
+}
+
+pub(crate) fn track_farthest<R, F>(f: F) -> (R, Farthest)
+where
+	F: FnOnce() -> R,
+{
+	let (tracking, farthest) = CURRENT.with(|current| {
+		(
+			current.tracking.replace(true),
+			current.farthest.replace(Farthest::new()),
+		)
+	});
+	let result = f();
+	let tracked = CURRENT.with(|current| {
+		current.tracking.set(tracking);
+		current.farthest.replace(farthest)
+	});
+	(result, tracked)
+}
+
+fn skip_trivia(src: &str, pos: &mut usize, skipper: &Skipper)
+{
+	let from = *pos;
+	{
+		let _guard = TriviaGuard::disable();
+		skipper(src, pos);
+	}
+	CURRENT.with(|current| {
+		if current.retain_trivia.get() && from < *pos
+		{
+			let mut retained = current.retained.borrow_mut();
+			while retained.last().is_some_and(|range| range.start >= from)
+			{
+				retained.pop();
+			}
+			retained.push(from..*pos);
+		}
+	})
+}
+
+pub(crate) fn lexeme<T, E, F>(src: &str, pos: &mut usize, f: F) -> Result<T, E>
+where
+	F: FnOnce(&mut usize) -> Result<T, E>,
+{
+	let trivia = CURRENT.with(|current| {
+		if current.trivia_enabled.get()
+		{
+			current.trivia.borrow().clone()
+		}
+		else
+		{
+			None
+		}
+	});
+	let (skipper, position) = match trivia
+	{
+		Some(trivia) => trivia,
+		None => return f(pos),
+	};
+	let from = *pos;
+	let retained = CURRENT.with(|current| current.retained.borrow().len());
+	if position == TriviaPosition::Before
+	{
+		skip_trivia(src, pos, &skipper);
+	}
+	match f(pos)
+	{
+		Ok(result) =>
+		{
+			if position == TriviaPosition::After
+			{
+				skip_trivia(src, pos, &skipper);
+			}
+			Ok(result)
+		}
+		Err(err) =>
+		{
+			*pos = from;
+			CURRENT.with(|current| current.retained.borrow_mut().truncate(retained));
+			Err(err)
+		}
+	}
+}
+
+struct TakenState(Option<Box<dyn Any>>);
+
+impl Drop for TakenState
+{
+	fn drop(&mut self)
+	{
+		CURRENT.with(|current| current.state.replace(self.0.take()));
+	}
+}
+
+// The state is moved out of the session while `f` runs,
+// so that nested accesses find no state instead of a borrowed cell.
+fn borrow_state<R, F>(f: F) -> Option<R>
+where
+	F: FnOnce(&mut dyn Any) -> R,
+{
+	let mut taken = TakenState(CURRENT.with(|current| current.state.take()));
+	taken.0.as_mut().map(|state| f(state.as_mut()))
+}
+
+pub fn with_state<S, R, F>(f: F) -> Option<R>
+where
+	S: 'static,
+	F: FnOnce(&S) -> R,
+{
+	borrow_state(|state| state.downcast_ref::<S>().map(f)).flatten()
+}
+
+pub fn modify_state<S, U, F>(f: F) -> bool
+where
+	S: 'static,
+	U: FnOnce(&mut S) + 'static,
+	F: FnOnce(&mut S) -> U,
+{
+	let undo = match borrow_state(|state| state.downcast_mut::<S>().map(f)).flatten()
+	{
+		Some(undo) => undo,
+		None => return false,
+	};
+	CURRENT.with(|current| {
+		current.journal.borrow_mut().push(Box::new(move |state| {
+			if let Some(state) = state.downcast_mut::<S>()
+			{
+				undo(state)
+			}
+		}))
+	});
+	true
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Checkpoint
+{
+	journal: usize,
+	captures: usize,
+	retained: usize,
+}
+
+pub(crate) fn checkpoint() -> Checkpoint
+{
+	CURRENT.with(|current| {
+		Checkpoint {
+			journal: current.journal.borrow().len(),
+			captures: current.captures.borrow().len(),
+			retained: current.retained.borrow().len(),
+		}
+	})
+}
+
+pub(crate) fn rollback(checkpoint: Checkpoint)
+{
+	let undos = CURRENT.with(|current| {
+		current.captures.borrow_mut().truncate(checkpoint.captures);
+		current.retained.borrow_mut().truncate(checkpoint.retained);
+		if current.journal.borrow().len() <= checkpoint.journal
+		{
+			return None;
+		}
+		Some(current.journal.borrow_mut().split_off(checkpoint.journal))
+	});
+	if let Some(undos) = undos
+	{
+		borrow_state(|state| {
+			for undo in undos.into_iter().rev()
+			{
+				undo(state);
+			}
+		});
+	}
+}
+
+pub(crate) fn capturing() -> bool
+{
+	CURRENT.with(|current| current.in_session.get() || current.rule_depth.get() > 0)
+}
+
+pub(crate) fn capture(name: &'static str, range: Range<usize>)
+{
+	if capturing()
+	{
+		CURRENT.with(|current| current.captures.borrow_mut().push((name, range)));
+	}
+}
+
+pub(crate) fn captured(name: &str) -> Option<Range<usize>>
+{
+	CURRENT.with(|current| {
+		current
+			.captures
+			.borrow()
+			.iter()
+			.rev()
+			.find(|(captured, _)| *captured == name)
+			.map(|(_, range)| range.clone())
+	})
+}
@@ -0,0 +1,119 @@
+use crate::{
+	string_literal_parser::StringLiteralParserRequirement,
+	Error,
+};
+use std::fmt::{
+	Display,
+	Formatter,
+	Result as FmtResult,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StringLiteralErrorKind
+{
+	Quote,
+	Unterminated,
+	Escape
+	{
+		from: usize,
+		to: usize,
+	},
+}
+
+#[derive(Debug)]
+pub struct StringLiteralParserError<'a>
+{
+	from: usize,
+	requirement: StringLiteralParserRequirement,
+	kind: StringLiteralErrorKind,
+	src: &'a str,
+}
+
+impl<'a> StringLiteralParserError<'a>
+{
+	pub fn new(
+		from: usize,
+		requirement: StringLiteralParserRequirement,
+		kind: StringLiteralErrorKind,
+		src: &'a str,
+	) -> Self
+	{
+		Self {
+			from,
+			requirement,
+			kind,
+			src,
+		}
+	}
+
+	pub fn kind(&self) -> StringLiteralErrorKind
+	{
+		self.kind
+	}
+}
+
+impl<'a> Error for StringLiteralParserError<'a>
+{
+	fn position(&self) -> usize
+	{
+		self.from
+	}
+
+	fn from(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "{}", self.from)
+	}
+
+	fn requirement(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "{}", self.requirement)
+	}
+
+	fn result(&self, f: &mut Formatter) -> FmtResult
+	{
+		match self.kind
+		{
+			StringLiteralErrorKind::Quote =>
+			{
+				match self.src[self.from..].chars().next()
+				{
+					Some(found) => write!(f, "{:?} found", found),
+					None => write!(f, "not found"),
+				}
+			}
+			StringLiteralErrorKind::Unterminated => write!(f, "unterminated literal found"),
+			StringLiteralErrorKind::Escape { from, to } =>
+			{
+				write!(
+					f,
+					"invalid escape {} found at position {}",
+					&self.src[from..to],
+					from
+				)
+			}
+		}
+	}
+
+	fn farthest(&self) -> usize
+	{
+		match self.kind
+		{
+			StringLiteralErrorKind::Quote => self.from,
+			StringLiteralErrorKind::Unterminated => self.src.len(),
+			StringLiteralErrorKind::Escape { from, .. } => from,
+		}
+	}
+
+	fn causes(&self, _: &mut Formatter, _: usize) -> FmtResult
+	{
+		Ok(())
+	}
+}
+
+impl<'a> Display for StringLiteralParserError<'a>
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		self.print(f, 0)
+	}
+}
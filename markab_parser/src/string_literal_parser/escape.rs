@@ -0,0 +1,165 @@
+use std::fmt::{
+	Display,
+	Formatter,
+	Result as FmtResult,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EscapeDialect
+{
+	Rust,
+	Json,
+	C,
+}
+
+fn including(src: &str, at: usize) -> usize
+{
+	at + src[at..].chars().next().map_or(0, char::len_utf8)
+}
+
+fn hex(src: &str, from: usize, min: usize, max: usize) -> Result<(u32, usize), usize>
+{
+	let mut value = 0u32;
+	let mut at = from;
+	for &byte in src.as_bytes()[from..].iter().take(max)
+	{
+		match (byte as char).to_digit(16)
+		{
+			Some(digit) => value = value * 16 + digit,
+			None => break,
+		}
+		at += 1;
+	}
+	if at - from < min
+	{
+		Err(including(src, at))
+	}
+	else
+	{
+		Ok((value, at))
+	}
+}
+
+fn scalar(value: u32, end: usize) -> Result<(Option<char>, usize), usize>
+{
+	char::from_u32(value).map(|c| (Some(c), end)).ok_or(end)
+}
+
+impl EscapeDialect
+{
+	pub(crate) fn unescape(self, src: &str, at: usize) -> Result<(Option<char>, usize), usize>
+	{
+		let next = match src[at + 1..].chars().next()
+		{
+			Some(next) => next,
+			None => return Err(at + 1),
+		};
+		let end = at + 1 + next.len_utf8();
+		let simple = |c: char| Ok((Some(c), end));
+		match (self, next)
+		{
+			(_, 'n') => simple('\n'),
+			(_, 'r') => simple('\r'),
+			(_, 't') => simple('\t'),
+			(_, '\\') => simple('\\'),
+			(_, '"') => simple('"'),
+			(EscapeDialect::Rust, '\'') | (EscapeDialect::C, '\'') => simple('\''),
+			(EscapeDialect::Rust, '0') => simple('\0'),
+			(EscapeDialect::Rust, 'x') =>
+			{
+				let (value, end) = hex(src, end, 2, 2)?;
+				if value > 0x7f
+				{
+					return Err(end);
+				}
+				scalar(value, end)
+			}
+			(EscapeDialect::Rust, 'u') =>
+			{
+				if src.as_bytes().get(end) != Some(&b'{')
+				{
+					return Err(including(src, end));
+				}
+				let (value, end) = hex(src, end + 1, 1, 6)?;
+				if src.as_bytes().get(end) != Some(&b'}')
+				{
+					return Err(including(src, end));
+				}
+				scalar(value, end + 1)
+			}
+			(EscapeDialect::Rust, '\n') =>
+			{
+				let skipped = src[end..].len() - src[end..].trim_start().len();
+				Ok((None, end + skipped))
+			}
+			(EscapeDialect::Json, '/') => simple('/'),
+			(EscapeDialect::Json, 'b') | (EscapeDialect::C, 'b') => simple('\u{8}'),
+			(EscapeDialect::Json, 'f') | (EscapeDialect::C, 'f') => simple('\u{c}'),
+			(EscapeDialect::Json, 'u') =>
+			{
+				let (high, end) = hex(src, end, 4, 4)?;
+				if !(0xd800..0xdc00).contains(&high)
+				{
+					return scalar(high, end);
+				}
+				if !src[end..].starts_with("\\u")
+				{
+					return Err(end);
+				}
+				let (low, end) = hex(src, end + 2, 4, 4)?;
+				if !(0xdc00..0xe000).contains(&low)
+				{
+					return Err(end);
+				}
+				scalar(0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00), end)
+			}
+			(EscapeDialect::C, 'a') => simple('\u{7}'),
+			(EscapeDialect::C, 'v') => simple('\u{b}'),
+			(EscapeDialect::C, '?') => simple('?'),
+			(EscapeDialect::C, '0'..='7') =>
+			{
+				let mut value = 0;
+				let mut end = at + 1;
+				for &byte in src.as_bytes()[at + 1..].iter().take(3)
+				{
+					if !(b'0'..=b'7').contains(&byte)
+					{
+						break;
+					}
+					value = value * 8 + u32::from(byte - b'0');
+					end += 1;
+				}
+				scalar(value, end)
+			}
+			(EscapeDialect::C, 'x') =>
+			{
+				let (value, end) = hex(src, end, 1, 6)?;
+				scalar(value, end)
+			}
+			(EscapeDialect::C, 'u') =>
+			{
+				let (value, end) = hex(src, end, 4, 4)?;
+				scalar(value, end)
+			}
+			(EscapeDialect::C, 'U') =>
+			{
+				let (value, end) = hex(src, end, 8, 8)?;
+				scalar(value, end)
+			}
+			_ => Err(end),
+		}
+	}
+}
+
+impl Display for EscapeDialect
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		match self
+		{
+			EscapeDialect::Rust => write!(f, "Rust"),
+			EscapeDialect::Json => write!(f, "JSON"),
+			EscapeDialect::C => write!(f, "C"),
+		}
+	}
+}
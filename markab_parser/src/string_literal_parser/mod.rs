@@ -0,0 +1,16 @@
+mod error;
+mod escape;
+mod parser;
+mod requirement;
+mod utility;
+
+pub use self::{
+	error::{
+		StringLiteralErrorKind,
+		StringLiteralParserError,
+	},
+	escape::EscapeDialect,
+	parser::StringLiteralParser,
+	requirement::StringLiteralParserRequirement,
+	utility::string_literal,
+};
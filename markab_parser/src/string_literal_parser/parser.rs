@@ -0,0 +1,263 @@
+use crate::{
+	scan,
+	session,
+	string_literal_parser::{
+		EscapeDialect,
+		StringLiteralErrorKind,
+		StringLiteralParserError,
+		StringLiteralParserRequirement,
+	},
+	Failure,
+	Needed,
+	Nullable,
+	Parser,
+};
+use std::borrow::Cow;
+
+#[derive(Debug)]
+pub struct StringLiteralParser<'a>
+{
+	dialect: EscapeDialect,
+	quotes: &'a [char],
+	raw: bool,
+}
+
+impl<'a> StringLiteralParser<'a>
+{
+	pub fn new(dialect: EscapeDialect) -> Self
+	{
+		Self {
+			dialect,
+			quotes: &['"'],
+			raw: false,
+		}
+	}
+
+	pub fn quotes(mut self, quotes: &'a [char]) -> Self
+	{
+		self.quotes = quotes;
+		self
+	}
+
+	pub fn raw(mut self, raw: bool) -> Self
+	{
+		self.raw = raw;
+		self
+	}
+
+	fn scan_raw(
+		&self,
+		src: &'a str,
+		from: usize,
+	) -> Option<Result<(Cow<'a, str>, usize), StringLiteralErrorKind>>
+	{
+		let rest = src[from..].strip_prefix('r')?;
+		let hashes = rest.len() - rest.trim_start_matches('#').len();
+		let quote = rest[hashes..]
+			.chars()
+			.next()
+			.filter(|c| self.quotes.contains(c))?;
+		let start = from + 1 + hashes + quote.len_utf8();
+		let mut at = start;
+		while let Some(end) = scan::find_literal(src, at, quote.encode_utf8(&mut [0; 4]))
+		{
+			let close = end + quote.len_utf8();
+			if src[close..]
+				.bytes()
+				.take(hashes)
+				.filter(|&byte| byte == b'#')
+				.count() == hashes
+			{
+				return Some(Ok((Cow::Borrowed(&src[start..end]), close + hashes)));
+			}
+			at = close;
+		}
+		Some(Err(StringLiteralErrorKind::Unterminated))
+	}
+
+	fn scan(
+		&self,
+		src: &'a str,
+		from: usize,
+	) -> Result<(Cow<'a, str>, usize), StringLiteralErrorKind>
+	{
+		if self.raw
+		{
+			if let Some(result) = self.scan_raw(src, from)
+			{
+				return result;
+			}
+		}
+		let quote = match src[from..].chars().next()
+		{
+			Some(quote) if self.quotes.contains(&quote) => quote,
+			_ => return Err(StringLiteralErrorKind::Quote),
+		};
+		let start = from + quote.len_utf8();
+		let mut owned: Option<String> = None;
+		let mut chunk = start;
+		let mut at = start;
+		loop
+		{
+			at = if quote.is_ascii()
+			{
+				scan::find_byte_set(src, at, &[quote as u8, b'\\'])
+			}
+			else
+			{
+				src[at..].find([quote, '\\']).map(|i| at + i)
+			}
+			.ok_or(StringLiteralErrorKind::Unterminated)?;
+			if src[at..].starts_with(quote)
+			{
+				let content = match owned
+				{
+					Some(mut owned) =>
+					{
+						owned.push_str(&src[chunk..at]);
+						Cow::Owned(owned)
+					}
+					None => Cow::Borrowed(&src[start..at]),
+				};
+				return Ok((content, at + quote.len_utf8()));
+			}
+			let (decoded, end) = self
+				.dialect
+				.unescape(src, at)
+				.map_err(|to| StringLiteralErrorKind::Escape { from: at, to })?;
+			let owned = owned.get_or_insert_with(String::new);
+			owned.push_str(&src[chunk..at]);
+			owned.extend(decoded);
+			at = end;
+			chunk = end;
+		}
+	}
+}
+
+fn reached_end(src: &str, from: usize, kind: StringLiteralErrorKind)
+{
+	match kind
+	{
+		StringLiteralErrorKind::Quote => session::reached_end(src, from, Needed::Size(1)),
+		StringLiteralErrorKind::Unterminated =>
+		{
+			session::reached_end(src, src.len(), Needed::Unknown)
+		}
+		StringLiteralErrorKind::Escape { to, .. } => session::reached_end(src, to, Needed::Unknown),
+	}
+}
+
+impl<'a> Parser<'a> for StringLiteralParser<'a>
+{
+	type Error = StringLiteralParserError<'a>;
+	type Output = Cow<'a, str>;
+	type Requirement = StringLiteralParserRequirement;
+	type RequirementContext = ();
+
+	fn parse(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Self::Error>
+	{
+		let src = session::metered(src, *pos);
+		session::lexeme(src, pos, |pos| {
+			let from = *pos;
+			match self.scan(src, from)
+			{
+				Ok((content, end)) =>
+				{
+					*pos = end;
+					Ok(content)
+				}
+				Err(kind) =>
+				{
+					session::record_failure(from, || self.requirement(None));
+					reached_end(src, from, kind);
+					Err(StringLiteralParserError::new(
+						from,
+						self.requirement(None),
+						kind,
+						src,
+					))
+				}
+			}
+		})
+	}
+
+	fn attempt(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Failure<'a>>
+	{
+		let src = session::metered(src, *pos);
+		session::lexeme(src, pos, |pos| {
+			let from = *pos;
+			match self.scan(src, from)
+			{
+				Ok((content, end)) =>
+				{
+					*pos = end;
+					Ok(content)
+				}
+				Err(kind) =>
+				{
+					session::record_failure(from, || self.requirement(None));
+					reached_end(src, from, kind);
+					Err(Failure::new(from))
+				}
+			}
+		})
+	}
+
+	fn nullable(&self) -> Nullable
+	{
+		Nullable::No
+	}
+
+	fn cheap_attempt(&self) -> bool
+	{
+		true
+	}
+
+	fn requirement(&self, _: Option<&Self::RequirementContext>) -> Self::Requirement
+	{
+		StringLiteralParserRequirement::new(self.dialect)
+	}
+}
+
+#[test]
+fn test()
+{
+	use crate::string_literal;
+
+	let rust = string_literal(EscapeDialect::Rust).raw(true);
+	let mut pos = 0;
+	let content = rust.parse(r#""plain" x"#, &mut pos).ok().unwrap();
+	assert!(matches!(content, Cow::Borrowed("plain")));
+	assert_eq!(pos, 7);
+	let mut pos = 0;
+	let content = rust.parse(r#""a\t\u{1F600}\"""#, &mut pos).ok().unwrap();
+	assert_eq!(content, "a\t\u{1F600}\"");
+	let mut pos = 0;
+	let content = rust.parse(r###"r#"say "hi""#"###, &mut pos).ok().unwrap();
+	assert!(matches!(content, Cow::Borrowed("say \"hi\"")));
+	let mut pos = 0;
+	let err = rust.parse(r#""ok \q""#, &mut pos).err().unwrap();
+	assert_eq!(
+		err.kind(),
+		StringLiteralErrorKind::Escape { from: 4, to: 6 }
+	);
+	assert_eq!(
+		format!("{}", err),
+		"at position 0 required Rust string literal but invalid escape \\q found at position 4.\n"
+	);
+
+	let json = string_literal(EscapeDialect::Json);
+	let mut pos = 0;
+	assert_eq!(
+		json.parse(r#""😀\/""#, &mut pos).ok().unwrap(),
+		"\u{1F600}/"
+	);
+	let mut pos = 0;
+	assert_eq!(
+		json.parse(r#""\ud83d""#, &mut pos).err().unwrap().kind(),
+		StringLiteralErrorKind::Escape { from: 1, to: 7 }
+	);
+	let c = string_literal(EscapeDialect::C).quotes(&['\'', '"']);
+	let mut pos = 0;
+	assert_eq!(c.parse(r"'\101\x42\n'", &mut pos).ok().unwrap(), "AB\n");
+}
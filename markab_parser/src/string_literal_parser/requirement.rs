@@ -0,0 +1,33 @@
+use crate::string_literal_parser::EscapeDialect;
+use std::fmt::{
+	Display,
+	Formatter,
+	Result as FmtResult,
+};
+
+#[derive(Debug)]
+pub struct StringLiteralParserRequirement
+{
+	dialect: EscapeDialect,
+}
+
+impl StringLiteralParserRequirement
+{
+	pub fn new(dialect: EscapeDialect) -> Self
+	{
+		Self { dialect }
+	}
+
+	pub fn dialect(&self) -> EscapeDialect
+	{
+		self.dialect
+	}
+}
+
+impl Display for StringLiteralParserRequirement
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "{} string literal", self.dialect)
+	}
+}
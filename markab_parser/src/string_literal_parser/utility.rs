@@ -0,0 +1,9 @@
+use crate::string_literal_parser::{
+	EscapeDialect,
+	StringLiteralParser,
+};
+
+pub fn string_literal<'a>(dialect: EscapeDialect) -> StringLiteralParser<'a>
+{
+	StringLiteralParser::new(dialect)
+}
@@ -27,6 +27,11 @@ impl<'a> StringParserError<'a>
 
 impl<'a> Error for StringParserError<'a>
 {
+	fn position(&self) -> usize
+	{
+		self.from
+	}
+
 	fn from(&self, f: &mut Formatter) -> FmtResult
 	{
 		write!(f, "{}", self.from)
@@ -39,9 +44,9 @@ impl<'a> Error for StringParserError<'a>
 
 	fn result(&self, f: &mut Formatter) -> FmtResult
 	{
-		for p in (1..self.requirement.len() + 1).rev()
+		for p in (self.from + 1..self.from + self.requirement.len() + 1).rev()
 		{
-			if self.src.is_char_boundary(p)
+			if p <= self.src.len() && self.src.is_char_boundary(p)
 			{
 				return write!(f, "{} found", &self.src[self.from..p]);
 			}
@@ -49,6 +54,23 @@ impl<'a> Error for StringParserError<'a>
 		write!(f, "not found")
 	}
 
+	fn literal(&self) -> Option<&str>
+	{
+		Some(self.requirement)
+	}
+
+	fn farthest(&self) -> usize
+	{
+		self.from
+			+ self
+				.requirement
+				.chars()
+				.zip(self.src[self.from..].chars())
+				.take_while(|(required, found)| required == found)
+				.map(|(required, _)| required.len_utf8())
+				.sum::<usize>()
+	}
+
 	fn causes(&self, _: &mut Formatter, _: usize) -> FmtResult
 	{
 		Ok(())
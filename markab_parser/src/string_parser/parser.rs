@@ -1,5 +1,9 @@
 use crate::{
+	scan,
+	session,
 	string_parser::StringParserError,
+	Failure,
+	Nullable,
 	Parser,
 };
 
@@ -26,17 +30,56 @@ impl<'a> Parser<'a> for StringParser<'a>
 
 	fn parse(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Self::Error>
 	{
-		let from = *pos;
-		let len = self.requirement.len();
-		if src[from..].starts_with(self.requirement)
-		{
-			*pos += len;
-			Ok(&src[from..*pos])
-		}
-		else
-		{
-			Err(StringParserError::new(from, self.requirement(None), src))
-		}
+		let src = session::metered(src, *pos);
+		session::lexeme(src, pos, |pos| {
+			let from = *pos;
+			let len = self.requirement.len();
+			if src[from..].starts_with(self.requirement)
+			{
+				*pos += len;
+				Ok(&src[from..*pos])
+			}
+			else
+			{
+				session::record_failure(from, || self.requirement(None));
+				session::reached_end_of_literal(src, from, self.requirement.chars(), |a, b| a == b);
+				Err(StringParserError::new(from, self.requirement(None), src))
+			}
+		})
+	}
+
+	fn attempt(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Failure<'a>>
+	{
+		let src = session::metered(src, *pos);
+		session::lexeme(src, pos, |pos| {
+			let from = *pos;
+			if src[from..].starts_with(self.requirement)
+			{
+				*pos += self.requirement.len();
+				Ok(&src[from..*pos])
+			}
+			else
+			{
+				session::record_failure(from, || self.requirement(None));
+				session::reached_end_of_literal(src, from, self.requirement.chars(), |a, b| a == b);
+				Err(Failure::new(from))
+			}
+		})
+	}
+
+	fn nullable(&self) -> Nullable
+	{
+		self.requirement.is_empty().into()
+	}
+
+	fn next_candidate(&self, src: &'a str, from: usize) -> Option<usize>
+	{
+		scan::find_literal(src, from, self.requirement)
+	}
+
+	fn cheap_attempt(&self) -> bool
+	{
+		true
 	}
 
 	fn requirement(&self, _: Option<&Self::RequirementContext>) -> Self::Requirement
@@ -1,6 +1,6 @@
 use crate::string_parser::StringParser;
 
-pub fn string(requirement: &str) -> StringParser
+pub fn string(requirement: &str) -> StringParser<'_>
 {
 	StringParser::new(requirement)
 }
@@ -37,6 +37,11 @@ impl<'a, P> Error for StringifyParserError<'a, P>
 where
 	P: Parser<'a>,
 {
+	fn position(&self) -> usize
+	{
+		self.from
+	}
+
 	fn from(&self, f: &mut Formatter) -> FmtResult
 	{
 		write!(f, "{}", self.from)
@@ -52,14 +57,24 @@ where
 		write!(f, "failed to parse")
 	}
 
+	fn farthest(&self) -> usize
+	{
+		self.err.farthest()
+	}
+
 	fn causes(&self, f: &mut Formatter, depth: usize) -> FmtResult
 	{
 		self.err.print(f, depth)
 	}
 
-	fn print(&self, f: &mut Formatter, depth: usize) -> FmtResult
+	fn for_each_cause<'s>(&'s self, f: &mut dyn FnMut(&'s dyn Error))
+	{
+		f(&self.err)
+	}
+
+	fn delegate(&self) -> Option<&dyn Error>
 	{
-		self.causes(f, depth)
+		Some(&self.err)
 	}
 }
 
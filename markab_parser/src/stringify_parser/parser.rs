@@ -3,6 +3,8 @@ use crate::{
 		StringifyParserError,
 		StringifyParserRequirement,
 	},
+	Failure,
+	Nullable,
 	Parser,
 };
 use std::marker::PhantomData;
@@ -47,8 +49,68 @@ where
 			.map_err(|err| StringifyParserError::new(from, self.requirement(None), err))
 	}
 
+	fn skip(&self, src: &'a str, pos: &mut usize) -> Result<(), Self::Error>
+	{
+		let from = *pos;
+		self.requirement
+			.skip(src, pos)
+			.map_err(|err| StringifyParserError::new(from, self.requirement(None), err))
+	}
+
+	fn attempt(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Failure<'a>>
+	{
+		let from = *pos;
+		self.requirement
+			.attempt(src, pos)
+			.map(|_| &src[from..*pos])
+			.map_err(|failure| failure.at(from))
+	}
+
+	fn nullable(&self) -> Nullable
+	{
+		self.requirement.nullable()
+	}
+
+	fn next_candidate(&self, src: &'a str, from: usize) -> Option<usize>
+	{
+		self.requirement.next_candidate(src, from)
+	}
+
+	fn skip_repeated(&self, src: &'a str, pos: &mut usize, max: usize) -> Option<usize>
+	{
+		self.requirement.skip_repeated(src, pos, max)
+	}
+
+	fn cheap_attempt(&self) -> bool
+	{
+		self.requirement.cheap_attempt()
+	}
+
 	fn requirement(&self, _: Option<&Self::RequirementContext>) -> Self::Requirement
 	{
 		StringifyParserRequirement::new(self.requirement.requirement(None))
 	}
 }
+
+#[test]
+fn test()
+{
+	use crate::{
+		character_class,
+		string,
+	};
+	let digits = character_class(false, &['0', '1', '2', '3', '4'], &[]).stringify();
+	let mut pos = 0;
+	assert_eq!(digits.skip_repeated("123a", &mut pos, usize::MAX), Some(3));
+	assert_eq!(pos, 3);
+	let parser = digits.zero_or_more().stringify();
+	let mut pos = 0;
+	assert_eq!(parser.parse("42 x", &mut pos).ok(), Some("42"));
+	let mut pos = 0;
+	assert!(parser.skip("42 x", &mut pos).is_ok());
+	assert_eq!(pos, 2);
+	let parser = string("ab").stringify();
+	let mut pos = 0;
+	assert!(parser.skip("ax", &mut pos).is_err());
+	assert_eq!(pos, 0);
+}
@@ -0,0 +1,84 @@
+use crate::{
+	take_until_parser::TakeUntilParserRequirement,
+	Error,
+	Parser,
+};
+use std::fmt::{
+	Display,
+	Formatter,
+	Result as FmtResult,
+};
+
+#[derive(Debug)]
+pub struct TakeUntilParserError<'a, P>
+where
+	P: Parser<'a>,
+{
+	from: usize,
+	requirement: TakeUntilParserRequirement<'a, P>,
+	farthest: usize,
+}
+
+impl<'a, P> TakeUntilParserError<'a, P>
+where
+	P: Parser<'a>,
+{
+	pub fn new(from: usize, requirement: TakeUntilParserRequirement<'a, P>, farthest: usize)
+		-> Self
+	{
+		Self {
+			from,
+			requirement,
+			farthest,
+		}
+	}
+}
+
+impl<'a, P> Error for TakeUntilParserError<'a, P>
+where
+	P: Parser<'a>,
+{
+	fn position(&self) -> usize
+	{
+		self.from
+	}
+
+	fn from(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "{}", self.from)
+	}
+
+	fn requirement(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "{}", self.requirement)
+	}
+
+	fn result(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(
+			f,
+			"reached end of input without finding {}",
+			self.requirement.terminator()
+		)
+	}
+
+	fn farthest(&self) -> usize
+	{
+		self.farthest
+	}
+
+	fn causes(&self, _: &mut Formatter, _: usize) -> FmtResult
+	{
+		Ok(())
+	}
+}
+
+impl<'a, P> Display for TakeUntilParserError<'a, P>
+where
+	P: Parser<'a>,
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		self.print(f, 0)
+	}
+}
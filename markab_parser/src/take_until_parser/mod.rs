@@ -0,0 +1,11 @@
+mod error;
+mod parser;
+mod requirement;
+mod utility;
+
+pub use self::{
+	error::TakeUntilParserError,
+	parser::TakeUntilParser,
+	requirement::TakeUntilParserRequirement,
+	utility::take_until,
+};
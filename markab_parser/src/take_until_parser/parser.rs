@@ -0,0 +1,171 @@
+use crate::{
+	session,
+	take_until_parser::{
+		TakeUntilParserError,
+		TakeUntilParserRequirement,
+	},
+	Failure,
+	Needed,
+	Nullable,
+	Parser,
+};
+use std::marker::PhantomData;
+
+#[derive(Debug)]
+pub struct TakeUntilParser<'a, P>
+where
+	P: Parser<'a>,
+{
+	terminator: P,
+	_a: PhantomData<&'a ()>,
+}
+
+impl<'a, P> TakeUntilParser<'a, P>
+where
+	P: Parser<'a>,
+{
+	pub fn new(terminator: P) -> Self
+	{
+		Self {
+			terminator,
+			_a: PhantomData,
+		}
+	}
+
+	fn scan(&self, src: &'a str, from: usize) -> Option<usize>
+	{
+		let mut at = from;
+		loop
+		{
+			let candidate = self.terminator.next_candidate(src, at)?;
+			let checkpoint = session::checkpoint();
+			let found = self.terminator.attempt(src, &mut candidate.clone()).is_ok();
+			session::rollback(checkpoint);
+			if found
+			{
+				return Some(candidate);
+			}
+			at = candidate + src[candidate..].chars().next()?.len_utf8();
+		}
+	}
+}
+
+impl<'a, P> Parser<'a> for TakeUntilParser<'a, P>
+where
+	P: Parser<'a>,
+{
+	type Error = TakeUntilParserError<'a, P>;
+	type Output = &'a str;
+	type Requirement = TakeUntilParserRequirement<'a, P>;
+	type RequirementContext = ();
+
+	fn parse(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Self::Error>
+	{
+		let src = session::metered(src, *pos);
+		let from = *pos;
+		match self.scan(src, from)
+		{
+			Some(end) =>
+			{
+				*pos = end;
+				Ok(&src[from..end])
+			}
+			None =>
+			{
+				session::reached_end(src, src.len(), Needed::Unknown);
+				session::record_failure(src.len(), || self.requirement(None));
+				Err(TakeUntilParserError::new(
+					from,
+					self.requirement(None),
+					src.len(),
+				))
+			}
+		}
+	}
+
+	fn attempt(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Failure<'a>>
+	{
+		let src = session::metered(src, *pos);
+		let from = *pos;
+		match self.scan(src, from)
+		{
+			Some(end) =>
+			{
+				*pos = end;
+				Ok(&src[from..end])
+			}
+			None =>
+			{
+				session::reached_end(src, src.len(), Needed::Unknown);
+				session::record_failure(src.len(), || self.requirement(None));
+				Err(Failure::new(from))
+			}
+		}
+	}
+
+	fn nullable(&self) -> Nullable
+	{
+		Nullable::Yes
+	}
+
+	fn cheap_attempt(&self) -> bool
+	{
+		self.terminator.cheap_attempt()
+	}
+
+	fn requirement(&self, _: Option<&Self::RequirementContext>) -> Self::Requirement
+	{
+		TakeUntilParserRequirement::new(self.terminator.requirement(None))
+	}
+}
+
+#[test]
+fn test()
+{
+	use crate::{
+		any_char,
+		end_of_input,
+		satisfy,
+		string,
+		take_until,
+		take_while,
+	};
+	let comment = string("/*")
+		.and_then(take_until(string("*/")))
+		.and_then(string("*/"));
+	let mut pos = 0;
+	assert_eq!(
+		comment
+			.parse("/* a * b */ c", &mut pos)
+			.ok()
+			.map(|((_, body), _)| body),
+		Some(" a * b ")
+	);
+	assert_eq!(pos, 11);
+	pos = 0;
+	let err = comment.parse("/* a", &mut pos).err().unwrap();
+	assert!(format!("{}", err).contains(
+		"at position 2 required (!*/ .)* &*/ but reached end of input without finding */."
+	));
+	let err = comment.zero_or_more().parse_complete("/* a").err().unwrap();
+	assert!(format!("{}", err)
+		.ends_with("\tat position 4 required (!*/ .)* &*/ but failed to parse.\n"));
+	let ident = satisfy("alphabetic", &char::is_alphabetic)
+		.and_then(take_while(
+			"alphanumeric",
+			&char::is_alphanumeric,
+			0,
+			usize::MAX,
+		))
+		.stringify();
+	pos = 0;
+	assert_eq!(ident.parse("ab12 c", &mut pos).ok(), Some("ab12"));
+	pos = 0;
+	assert_eq!(
+		any_char()
+			.and_then(end_of_input())
+			.parse("あ", &mut pos)
+			.ok(),
+		Some(("あ", ()))
+	);
+}
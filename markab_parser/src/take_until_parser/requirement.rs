@@ -0,0 +1,39 @@
+use crate::Parser;
+use std::fmt::{
+	Display,
+	Formatter,
+	Result as FmtResult,
+};
+
+#[derive(Debug)]
+pub struct TakeUntilParserRequirement<'a, P>
+where
+	P: Parser<'a>,
+{
+	terminator: P::Requirement,
+}
+
+impl<'a, P> TakeUntilParserRequirement<'a, P>
+where
+	P: Parser<'a>,
+{
+	pub fn new(terminator: P::Requirement) -> Self
+	{
+		Self { terminator }
+	}
+
+	pub fn terminator(&self) -> &P::Requirement
+	{
+		&self.terminator
+	}
+}
+
+impl<'a, P> Display for TakeUntilParserRequirement<'a, P>
+where
+	P: Parser<'a>,
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "(!{} .)* &{}", self.terminator, self.terminator)
+	}
+}
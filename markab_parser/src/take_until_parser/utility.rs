@@ -0,0 +1,11 @@
+use crate::{
+	take_until_parser::TakeUntilParser,
+	Parser,
+};
+
+pub fn take_until<'a, P>(terminator: P) -> TakeUntilParser<'a, P>
+where
+	P: Parser<'a>,
+{
+	TakeUntilParser::new(terminator)
+}
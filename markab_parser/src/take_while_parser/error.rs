@@ -0,0 +1,77 @@
+use crate::{
+	take_while_parser::TakeWhileParserRequirement,
+	Error,
+};
+use std::fmt::{
+	Display,
+	Formatter,
+	Result as FmtResult,
+};
+
+#[derive(Debug)]
+pub struct TakeWhileParserError<'a>
+{
+	from: usize,
+	requirement: TakeWhileParserRequirement<'a>,
+	found: usize,
+	farthest: usize,
+}
+
+impl<'a> TakeWhileParserError<'a>
+{
+	pub fn new(
+		from: usize,
+		requirement: TakeWhileParserRequirement<'a>,
+		found: usize,
+		farthest: usize,
+	) -> Self
+	{
+		Self {
+			from,
+			requirement,
+			found,
+			farthest,
+		}
+	}
+}
+
+impl<'a> Error for TakeWhileParserError<'a>
+{
+	fn position(&self) -> usize
+	{
+		self.from
+	}
+
+	fn from(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "{}", self.from)
+	}
+
+	fn requirement(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "{}", self.requirement)
+	}
+
+	fn result(&self, f: &mut Formatter) -> FmtResult
+	{
+		write!(f, "succeed in matching only {} character(s)", self.found)
+	}
+
+	fn farthest(&self) -> usize
+	{
+		self.farthest
+	}
+
+	fn causes(&self, _: &mut Formatter, _: usize) -> FmtResult
+	{
+		Ok(())
+	}
+}
+
+impl<'a> Display for TakeWhileParserError<'a>
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		self.print(f, 0)
+	}
+}
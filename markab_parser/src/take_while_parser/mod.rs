@@ -0,0 +1,11 @@
+mod error;
+mod parser;
+mod requirement;
+mod utility;
+
+pub use self::{
+	error::TakeWhileParserError,
+	parser::TakeWhileParser,
+	requirement::TakeWhileParserRequirement,
+	utility::take_while,
+};
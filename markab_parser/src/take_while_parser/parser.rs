@@ -0,0 +1,141 @@
+use crate::{
+	session,
+	take_while_parser::{
+		TakeWhileParserError,
+		TakeWhileParserRequirement,
+	},
+	Failure,
+	Needed,
+	Nullable,
+	Parser,
+};
+use std::fmt::{
+	Debug,
+	Formatter,
+	Result as FmtResult,
+};
+
+pub struct TakeWhileParser<'a>
+{
+	requirement: &'a str,
+	predicate: &'a dyn Fn(char) -> bool,
+	min: usize,
+	max: usize,
+}
+
+impl<'a> TakeWhileParser<'a>
+{
+	pub fn new(
+		requirement: &'a str,
+		predicate: &'a dyn Fn(char) -> bool,
+		min: usize,
+		max: usize,
+	) -> Self
+	{
+		Self {
+			requirement,
+			predicate,
+			min,
+			max,
+		}
+	}
+
+	fn scan(&self, src: &str, from: usize) -> (usize, usize)
+	{
+		let mut count = 0;
+		let mut end = from;
+		for c in src[from..].chars()
+		{
+			if count == self.max || !(self.predicate)(c)
+			{
+				break;
+			}
+			count += 1;
+			end += c.len_utf8();
+		}
+		(count, end)
+	}
+}
+
+impl<'a> Debug for TakeWhileParser<'a>
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		f.debug_struct("TakeWhileParser")
+			.field("requirement", &self.requirement)
+			.field("predicate", &"..")
+			.field("min", &self.min)
+			.field("max", &self.max)
+			.finish()
+	}
+}
+
+impl<'a> Parser<'a> for TakeWhileParser<'a>
+{
+	type Error = TakeWhileParserError<'a>;
+	type Output = &'a str;
+	type Requirement = TakeWhileParserRequirement<'a>;
+	type RequirementContext = ();
+
+	fn parse(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Self::Error>
+	{
+		let src = session::metered(src, *pos);
+		let from = *pos;
+		let (count, end) = self.scan(src, from);
+		if count < self.max
+		{
+			session::reached_end(src, end, Needed::Unknown);
+		}
+		if count < self.min
+		{
+			session::record_failure(end, || self.requirement);
+			Err(TakeWhileParserError::new(
+				from,
+				self.requirement(None),
+				count,
+				end,
+			))
+		}
+		else
+		{
+			*pos = end;
+			Ok(&src[from..end])
+		}
+	}
+
+	fn attempt(&self, src: &'a str, pos: &mut usize) -> Result<Self::Output, Failure<'a>>
+	{
+		let src = session::metered(src, *pos);
+		let from = *pos;
+		let (count, end) = self.scan(src, from);
+		if count < self.max
+		{
+			session::reached_end(src, end, Needed::Unknown);
+		}
+		if count < self.min
+		{
+			session::record_failure(end, || self.requirement);
+			Err(Failure::new(from))
+		}
+		else
+		{
+			*pos = end;
+			Ok(&src[from..end])
+		}
+	}
+
+	fn nullable(&self) -> Nullable
+	{
+		(self.min == 0).into()
+	}
+
+	fn cheap_attempt(&self) -> bool
+	{
+		true
+	}
+
+	fn requirement(&self, _: Option<&Self::RequirementContext>) -> Self::Requirement
+	{
+		TakeWhileParserRequirement::new(self.requirement, self.min, self.max)
+	}
+}
@@ -0,0 +1,40 @@
+use std::fmt::{
+	Display,
+	Formatter,
+	Result as FmtResult,
+};
+
+#[derive(Debug)]
+pub struct TakeWhileParserRequirement<'a>
+{
+	requirement: &'a str,
+	min: usize,
+	max: usize,
+}
+
+impl<'a> TakeWhileParserRequirement<'a>
+{
+	pub fn new(requirement: &'a str, min: usize, max: usize) -> Self
+	{
+		Self {
+			requirement,
+			min,
+			max,
+		}
+	}
+}
+
+impl<'a> Display for TakeWhileParserRequirement<'a>
+{
+	fn fmt(&self, f: &mut Formatter) -> FmtResult
+	{
+		match (self.min, self.max)
+		{
+			(0, 1) => write!(f, "{}?", self.requirement),
+			(0, usize::MAX) => write!(f, "{}*", self.requirement),
+			(1, usize::MAX) => write!(f, "{}+", self.requirement),
+			(i, j) if i == j => write!(f, "{}{{{}}}", self.requirement, i),
+			(i, j) => write!(f, "{}{{{},{}}}", self.requirement, i, j),
+		}
+	}
+}
@@ -0,0 +1,11 @@
+use crate::take_while_parser::TakeWhileParser;
+
+pub fn take_while<'a>(
+	requirement: &'a str,
+	predicate: &'a dyn Fn(char) -> bool,
+	min: usize,
+	max: usize,
+) -> TakeWhileParser<'a>
+{
+	TakeWhileParser::new(requirement, predicate, min, max)
+}